@@ -0,0 +1,39 @@
+use crate::prelude::*;
+use ruint::algorithms::{add_assign_slice, sub_assign_slice};
+
+pub fn group(criterion: &mut Criterion) {
+    bench_add_assign_slice(criterion);
+    bench_sub_assign_slice(criterion);
+}
+
+fn bench_add_assign_slice(criterion: &mut Criterion) {
+    const_for!(SIZE in [1,2,3,4,5,6] {
+        let mut rng = rand::thread_rng();
+        criterion.bench_function(&format!("algo/add_assign_slice/{SIZE}"), move |bencher| {
+            bencher.iter_batched(
+                || (rng.gen::<[u64; SIZE]>(), rng.gen::<[u64; SIZE]>()),
+                |(mut dst, src)| {
+                    black_box(add_assign_slice(&mut dst, &src));
+                    black_box(dst)
+                },
+                BatchSize::SmallInput,
+            );
+        });
+    });
+}
+
+fn bench_sub_assign_slice(criterion: &mut Criterion) {
+    const_for!(SIZE in [1,2,3,4,5,6] {
+        let mut rng = rand::thread_rng();
+        criterion.bench_function(&format!("algo/sub_assign_slice/{SIZE}"), move |bencher| {
+            bencher.iter_batched(
+                || (rng.gen::<[u64; SIZE]>(), rng.gen::<[u64; SIZE]>()),
+                |(mut dst, src)| {
+                    black_box(sub_assign_slice(&mut dst, &src));
+                    black_box(dst)
+                },
+                BatchSize::SmallInput,
+            );
+        });
+    });
+}
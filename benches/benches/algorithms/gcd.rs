@@ -1,6 +1,6 @@
 use crate::prelude::*;
 use core::cmp::{max, min};
-use ruint::algorithms::LehmerMatrix as Matrix;
+use ruint::algorithms::{gcd_binary, gcd_lehmer, LehmerMatrix as Matrix};
 
 pub fn group(criterion: &mut Criterion) {
     bench_from_u64(criterion);
@@ -8,6 +8,7 @@ pub fn group(criterion: &mut Criterion) {
     const_for!(BITS in BENCH {
         const LIMBS: usize = nlimbs(BITS);
         bench_apply::<BITS, LIMBS>(criterion);
+        bench_gcd::<BITS, LIMBS>(criterion);
     });
 }
 
@@ -63,3 +64,26 @@ fn bench_apply<const BITS: usize, const LIMBS: usize>(criterion: &mut Criterion)
         },
     );
 }
+
+fn bench_gcd<const BITS: usize, const LIMBS: usize>(criterion: &mut Criterion) {
+    let input = (
+        Uint::<BITS, LIMBS>::arbitrary(),
+        Uint::<BITS, LIMBS>::arbitrary(),
+    );
+    let mut runner = TestRunner::deterministic();
+    criterion.bench_function(&format!("algorithms/gcd/lehmer/{BITS}"), |bencher| {
+        bencher.iter_batched(
+            || input.new_tree(&mut runner).unwrap().current(),
+            |(a, b)| black_box(gcd_lehmer(black_box(a), black_box(b))),
+            BatchSize::SmallInput,
+        );
+    });
+    let mut runner = TestRunner::deterministic();
+    criterion.bench_function(&format!("algorithms/gcd/binary/{BITS}"), |bencher| {
+        bencher.iter_batched(
+            || input.new_tree(&mut runner).unwrap().current(),
+            |(a, b)| black_box(gcd_binary(black_box(a), black_box(b))),
+            BatchSize::SmallInput,
+        );
+    });
+}
@@ -1,8 +1,10 @@
+mod add;
 mod div;
 mod gcd;
 mod mul;
 
 pub fn group(criterion: &mut criterion::Criterion) {
+    self::add::group(criterion);
     self::mul::group(criterion);
     self::div::group(criterion);
     self::gcd::group(criterion);
@@ -0,0 +1,20 @@
+use crate::prelude::*;
+
+pub fn group(criterion: &mut Criterion) {
+    const_for!(BITS in BENCH {
+        const LIMBS: usize = nlimbs(BITS);
+        bench_count_ones::<BITS, LIMBS>(criterion);
+    });
+}
+
+fn bench_count_ones<const BITS: usize, const LIMBS: usize>(criterion: &mut Criterion) {
+    let input = Uint::<BITS, LIMBS>::arbitrary();
+    let mut runner = TestRunner::deterministic();
+    criterion.bench_function(&format!("count_ones/{BITS}"), move |bencher| {
+        bencher.iter_batched(
+            || input.new_tree(&mut runner).unwrap().current(),
+            |n| black_box(black_box(n).count_ones()),
+            BatchSize::SmallInput,
+        );
+    });
+}
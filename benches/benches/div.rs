@@ -1,11 +1,16 @@
 use crate::prelude::*;
+use ruint::Reciprocal;
 
 pub fn group(criterion: &mut Criterion) {
     const_for!(BITS in BENCH {
         const LIMBS: usize = nlimbs(BITS);
         bench_div_rem_small::<BITS, LIMBS>(criterion);
+        bench_div_rem_u64::<BITS, LIMBS>(criterion);
         bench_div_rem_half::<BITS, LIMBS>(criterion);
         bench_div_rem_full::<BITS, LIMBS>(criterion);
+        bench_div_rem_128::<BITS, LIMBS>(criterion);
+        bench_rem_128::<BITS, LIMBS>(criterion);
+        bench_div_rem_reciprocal::<BITS, LIMBS>(criterion);
     });
 }
 
@@ -33,6 +38,24 @@ fn bench_div_rem_small<const BITS: usize, const LIMBS: usize>(criterion: &mut Cr
     });
 }
 
+fn bench_div_rem_u64<const BITS: usize, const LIMBS: usize>(criterion: &mut Criterion) {
+    if BITS == 0 {
+        return;
+    }
+    let input = (Uint::<BITS, LIMBS>::arbitrary(), u64::arbitrary());
+    let mut runner = TestRunner::deterministic();
+    criterion.bench_function(&format!("div_rem_u64/{BITS}"), move |bencher| {
+        bencher.iter_batched(
+            || {
+                let (n, d) = input.new_tree(&mut runner).unwrap().current();
+                (n, d.max(1))
+            },
+            |(a, b)| black_box(black_box(a).div_rem_u64(black_box(b))),
+            BatchSize::SmallInput,
+        );
+    });
+}
+
 fn bench_div_rem_half<const BITS: usize, const LIMBS: usize>(criterion: &mut Criterion) {
     if BITS == 0 {
         return;
@@ -78,3 +101,60 @@ fn bench_div_rem_full<const BITS: usize, const LIMBS: usize>(criterion: &mut Cri
         );
     });
 }
+
+fn bench_div_rem_128<const BITS: usize, const LIMBS: usize>(criterion: &mut Criterion) {
+    // Skip sizes already covered by `bench_div_rem_half`.
+    if BITS < 128 || BITS - BITS / 2 == 128 {
+        return;
+    }
+    let input = (Uint::<BITS, LIMBS>::arbitrary(), u128::arbitrary());
+    let mut runner = TestRunner::deterministic();
+    criterion.bench_function(&format!("div_rem/{BITS}/128"), move |bencher| {
+        bencher.iter_batched(
+            || {
+                let (n, d) = input.new_tree(&mut runner).unwrap().current();
+                (n, Uint::from(d | (1 << 64)))
+            },
+            |(a, b)| black_box(black_box(a).div_rem(black_box(b))),
+            BatchSize::SmallInput,
+        );
+    });
+}
+
+fn bench_rem_128<const BITS: usize, const LIMBS: usize>(criterion: &mut Criterion) {
+    if BITS < 128 {
+        return;
+    }
+    let input = (Uint::<BITS, LIMBS>::arbitrary(), u128::arbitrary());
+    let mut runner = TestRunner::deterministic();
+    criterion.bench_function(&format!("rem/{BITS}/128"), move |bencher| {
+        bencher.iter_batched(
+            || {
+                let (n, d) = input.new_tree(&mut runner).unwrap().current();
+                (n, Uint::from(d | (1 << 64)))
+            },
+            |(a, b)| black_box(black_box(a) % black_box(b)),
+            BatchSize::SmallInput,
+        );
+    });
+}
+
+fn bench_div_rem_reciprocal<const BITS: usize, const LIMBS: usize>(criterion: &mut Criterion) {
+    if BITS == 0 {
+        return;
+    }
+    // A fixed divisor, like the largest power of ten in a `u64` used in base
+    // conversion.
+    let reciprocal = Reciprocal::new(Uint::<BITS, LIMBS>::wrapping_from(
+        10_000_000_000_000_000_000_u64,
+    ));
+    let input = Uint::<BITS, LIMBS>::arbitrary();
+    let mut runner = TestRunner::deterministic();
+    criterion.bench_function(&format!("div_rem_reciprocal/{BITS}/64"), move |bencher| {
+        bencher.iter_batched(
+            || input.new_tree(&mut runner).unwrap().current(),
+            |a| black_box(reciprocal.div_rem(black_box(a))),
+            BatchSize::SmallInput,
+        );
+    });
+}
@@ -0,0 +1,36 @@
+use crate::prelude::*;
+
+pub fn group(criterion: &mut Criterion) {
+    const_for!(BITS in BENCH {
+        const LIMBS: usize = nlimbs(BITS);
+        bench_display::<BITS, LIMBS>(criterion);
+    });
+}
+
+fn bench_display<const BITS: usize, const LIMBS: usize>(criterion: &mut Criterion) {
+    let input = Uint::<BITS, LIMBS>::arbitrary();
+    let mut runner = TestRunner::deterministic();
+    criterion.bench_function(&format!("fmt/display/{BITS}"), |bencher| {
+        bencher.iter_batched(
+            || input.new_tree(&mut runner).unwrap().current(),
+            |value| black_box(black_box(value).to_string()),
+            BatchSize::SmallInput,
+        );
+    });
+    // Baseline with one big integer division per digit.
+    let mut runner = TestRunner::deterministic();
+    criterion.bench_function(&format!("fmt/display_per_digit/{BITS}"), |bencher| {
+        bencher.iter_batched(
+            || input.new_tree(&mut runner).unwrap().current(),
+            |value| {
+                black_box(
+                    black_box(value)
+                        .to_base_be(10)
+                        .map(|digit| char::from_digit(u32::try_from(digit).unwrap(), 10).unwrap())
+                        .collect::<String>(),
+                )
+            },
+            BatchSize::SmallInput,
+        );
+    });
+}
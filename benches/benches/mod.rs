@@ -1,6 +1,8 @@
 mod add;
 mod algorithms;
+mod bits;
 mod div;
+mod fmt;
 mod log;
 mod modular;
 mod mul;
@@ -11,9 +13,11 @@ pub fn group(c: &mut criterion::Criterion) {
     add::group(c);
     mul::group(c);
     div::group(c);
+    bits::group(c);
     pow::group(c);
     log::group(c);
     root::group(c);
     modular::group(c);
     algorithms::group(c);
+    fmt::group(c);
 }
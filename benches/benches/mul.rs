@@ -4,6 +4,7 @@ pub fn group(criterion: &mut Criterion) {
     const_for!(BITS in BENCH {
         const LIMBS: usize = nlimbs(BITS);
         bench_mul::<BITS, LIMBS>(criterion);
+        bench_square::<BITS, LIMBS>(criterion);
     });
     const_for!(BITS_LHS in [64, 256,1024] {
         const LIMBS_LHS: usize = nlimbs(BITS_LHS);
@@ -28,6 +29,18 @@ fn bench_mul<const BITS: usize, const LIMBS: usize>(criterion: &mut Criterion) {
     });
 }
 
+fn bench_square<const BITS: usize, const LIMBS: usize>(criterion: &mut Criterion) {
+    let input = Uint::<BITS, LIMBS>::arbitrary();
+    let mut runner = TestRunner::deterministic();
+    criterion.bench_function(&format!("square/{BITS}"), move |bencher| {
+        bencher.iter_batched(
+            || input.new_tree(&mut runner).unwrap().current(),
+            |a| black_box(black_box(a).wrapping_square()),
+            BatchSize::SmallInput,
+        );
+    });
+}
+
 fn bench_widening_mul<
     const BITS_LHS: usize,
     const LIMBS_LHS: usize,
@@ -5,6 +5,7 @@ pub fn group(criterion: &mut Criterion) {
         const LIMBS: usize = nlimbs(BITS);
         bench_pow::<BITS, LIMBS>(criterion);
         bench_overflowing_pow::<BITS, LIMBS>(criterion);
+        bench_checked_pow::<BITS, LIMBS>(criterion);
     });
 }
 
@@ -37,3 +38,22 @@ fn bench_overflowing_pow<const BITS: usize, const LIMBS: usize>(criterion: &mut
         );
     });
 }
+
+fn bench_checked_pow<const BITS: usize, const LIMBS: usize>(criterion: &mut Criterion) {
+    // Small bases with small exponents, so the result mostly does not overflow.
+    let input = (2_u64..16, 0_usize..=BITS / 4);
+    let mut runner = TestRunner::deterministic();
+    criterion.bench_function(&format!("checked_pow/{BITS}"), move |bencher| {
+        bencher.iter_batched(
+            || {
+                let (b, e) = input.new_tree(&mut runner).unwrap().current();
+                (
+                    Uint::<BITS, LIMBS>::wrapping_from(b),
+                    Uint::<BITS, LIMBS>::wrapping_from(e),
+                )
+            },
+            |(b, e)| black_box(black_box(b).checked_pow(black_box(e))),
+            BatchSize::SmallInput,
+        );
+    });
+}
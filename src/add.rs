@@ -68,11 +68,155 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
             (self.limbs[i], carry) = carrying_add(self.limbs[i], rhs.limbs[i], carry);
             i += 1;
         }
+        #[allow(clippy::needless_bitwise_bool)] // Branch-free.
         let overflow = carry | (self.limbs[LIMBS - 1] > Self::MASK);
         self.limbs[LIMBS - 1] &= Self::MASK;
         (self, overflow)
     }
 
+    /// Calculates $\mod{\mathtt{self} + \mathtt{rhs}}_{2^{BITS}}$ for an `rhs`
+    /// of a different width.
+    ///
+    /// This is meant for accumulating narrow values (e.g. [`U64`] deltas) into
+    /// a wide [`Uint`] without first converting them. The shorter operand is
+    /// zero-extended. Returns the wrapped sum and whether overflow occurred,
+    /// which includes the case where `rhs` itself does not fit in `BITS`.
+    ///
+    /// [`U64`]: crate::aliases::U64
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(1_U256.overflowing_add_small(2_U64), (3_U256, false));
+    /// assert_eq!(U256::MAX.overflowing_add_small(2_U64), (1_U256, true));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn overflowing_add_small<const BITS_RHS: usize, const LIMBS_RHS: usize>(
+        mut self,
+        rhs: Uint<BITS_RHS, LIMBS_RHS>,
+    ) -> (Self, bool) {
+        let mut carry = false;
+        let mut i = 0;
+        while i < LIMBS && (i < LIMBS_RHS || carry) {
+            let limb = if i < LIMBS_RHS { rhs.limbs[i] } else { 0 };
+            (self.limbs[i], carry) = carrying_add(self.limbs[i], limb, carry);
+            i += 1;
+        }
+        let mut overflow = carry;
+        if LIMBS > 0 {
+            overflow |= self.limbs[LIMBS - 1] > Self::MASK;
+            self.limbs[LIMBS - 1] &= Self::MASK;
+        }
+        while i < LIMBS_RHS {
+            overflow |= rhs.limbs[i] != 0;
+            i += 1;
+        }
+        (self, overflow)
+    }
+
+    /// Calculates $\mod{\mathtt{self} - \mathtt{rhs}}_{2^{BITS}}$ for an `rhs`
+    /// of a different width.
+    ///
+    /// See [`Self::overflowing_add_small`].
+    #[inline]
+    #[must_use]
+    pub const fn overflowing_sub_small<const BITS_RHS: usize, const LIMBS_RHS: usize>(
+        mut self,
+        rhs: Uint<BITS_RHS, LIMBS_RHS>,
+    ) -> (Self, bool) {
+        let mut borrow = false;
+        let mut i = 0;
+        while i < LIMBS && (i < LIMBS_RHS || borrow) {
+            let limb = if i < LIMBS_RHS { rhs.limbs[i] } else { 0 };
+            (self.limbs[i], borrow) = borrowing_sub(self.limbs[i], limb, borrow);
+            i += 1;
+        }
+        let mut overflow = borrow;
+        if LIMBS > 0 {
+            overflow |= self.limbs[LIMBS - 1] > Self::MASK;
+            self.limbs[LIMBS - 1] &= Self::MASK;
+        }
+        while i < LIMBS_RHS {
+            overflow |= rhs.limbs[i] != 0;
+            i += 1;
+        }
+        (self, overflow)
+    }
+
+    /// Calculates $\mod{\mathtt{self} + \mathtt{rhs}}_{2^{BITS}}$ for a single
+    /// limb `rhs`.
+    ///
+    /// Returns the wrapped sum and whether overflow occurred. This is
+    /// [`Self::overflowing_add_small`] specialized to a `u64` operand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(1_U256.overflowing_add_u64(2), (3_U256, false));
+    /// assert_eq!(U256::MAX.overflowing_add_u64(2), (1_U256, true));
+    /// assert_eq!(1_U8.overflowing_add_u64(256), (1_U8, true));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn overflowing_add_u64(self, rhs: u64) -> (Self, bool) {
+        self.overflowing_add_small(Uint::<64, 1>::from_limbs([rhs]))
+    }
+
+    /// Computes `self + rhs` for a single limb `rhs`, returning [`None`] if
+    /// overflow occurred.
+    #[inline(always)]
+    #[must_use]
+    pub const fn checked_add_u64(self, rhs: u64) -> Option<Self> {
+        match self.overflowing_add_u64(rhs) {
+            (value, false) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Computes `self + rhs` for a single limb `rhs`, wrapping around at the
+    /// boundary of the type.
+    #[inline(always)]
+    #[must_use]
+    pub const fn wrapping_add_u64(self, rhs: u64) -> Self {
+        self.overflowing_add_u64(rhs).0
+    }
+
+    /// Calculates $\mod{\mathtt{self} - \mathtt{rhs}}_{2^{BITS}}$ for a single
+    /// limb `rhs`.
+    ///
+    /// See [`Self::overflowing_add_u64`].
+    #[inline]
+    #[must_use]
+    pub const fn overflowing_sub_u64(self, rhs: u64) -> (Self, bool) {
+        self.overflowing_sub_small(Uint::<64, 1>::from_limbs([rhs]))
+    }
+
+    /// Computes `self - rhs` for a single limb `rhs`, returning [`None`] if
+    /// overflow occurred.
+    #[inline(always)]
+    #[must_use]
+    pub const fn checked_sub_u64(self, rhs: u64) -> Option<Self> {
+        match self.overflowing_sub_u64(rhs) {
+            (value, false) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Computes `self - rhs` for a single limb `rhs`, wrapping around at the
+    /// boundary of the type.
+    #[inline(always)]
+    #[must_use]
+    pub const fn wrapping_sub_u64(self, rhs: u64) -> Self {
+        self.overflowing_sub_u64(rhs).0
+    }
+
     /// Calculates $\mod{-\mathtt{self}}_{2^{BITS}}$.
     ///
     /// Returns `!self + 1` using wrapping operations to return the value that
@@ -102,6 +246,7 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
             (self.limbs[i], borrow) = borrowing_sub(self.limbs[i], rhs.limbs[i], borrow);
             i += 1;
         }
+        #[allow(clippy::needless_bitwise_bool)] // Branch-free.
         let overflow = borrow | (self.limbs[LIMBS - 1] > Self::MASK);
         self.limbs[LIMBS - 1] &= Self::MASK;
         (self, overflow)
@@ -129,6 +274,60 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         }
     }
 
+    /// Adds `delta` to `self` in place, saturating at [`Self::MAX`].
+    ///
+    /// Returns `true` if the sum saturated. This is convenient for counters
+    /// that must never wrap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// let mut counter = 250_U8;
+    /// assert!(!counter.saturating_accumulate(5_U8));
+    /// assert_eq!(counter, 255_U8);
+    /// assert!(counter.saturating_accumulate(1_U8));
+    /// assert_eq!(counter, U8::MAX);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn saturating_accumulate(&mut self, delta: Self) -> bool {
+        let (value, overflow) = self.overflowing_add(delta);
+        *self = if overflow { Self::MAX } else { value };
+        overflow
+    }
+
+    /// Computes the sum of `values`, returning [`None`] if overflow occurred.
+    ///
+    /// Unlike the [`Sum`] implementation, which wraps, this gives an explicit
+    /// overflow signal. The sum of an empty slice is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(U8::checked_sum(&[100_U8, 100_U8, 55_U8]), Some(255_U8));
+    /// assert_eq!(U8::checked_sum(&[100_U8, 100_U8, 56_U8]), None);
+    /// assert_eq!(U8::checked_sum(&[]), Some(0_U8));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn checked_sum(values: &[Self]) -> Option<Self> {
+        let mut sum = Self::ZERO;
+        let mut i = 0;
+        while i < values.len() {
+            sum = match sum.checked_add(values[i]) {
+                Some(value) => value,
+                None => return None,
+            };
+            i += 1;
+        }
+        Some(sum)
+    }
+
     /// Computes `self + rhs`, wrapping around at the boundary of the type.
     #[inline(always)]
     #[must_use]
@@ -195,7 +394,7 @@ impl_bin_op!(Sub, sub, SubAssign, sub_assign, wrapping_sub);
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{const_for, nlimbs};
+    use crate::{aliases::U64, const_for, nlimbs};
     use proptest::proptest;
 
     #[test]
@@ -219,6 +418,98 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_mixed_width() {
+        fn check<
+            const BITS: usize,
+            const LIMBS: usize,
+            const BITS_RHS: usize,
+            const LIMBS_RHS: usize,
+        >(
+            a: Uint<BITS, LIMBS>,
+            b: Uint<BITS_RHS, LIMBS_RHS>,
+        ) {
+            let (b_wide, truncated) =
+                Uint::<BITS, LIMBS>::overflowing_from_limbs_slice(b.as_limbs());
+            let (sum, overflow) = a.overflowing_add(b_wide);
+            assert_eq!(a.overflowing_add_small(b), (sum, overflow || truncated));
+            let (diff, overflow) = a.overflowing_sub(b_wide);
+            assert_eq!(a.overflowing_sub_small(b), (diff, overflow || truncated));
+        }
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: Uint<64, 1>, c: Uint<7, 1>, d: Uint<130, 3>)| {
+                check(a, b);
+                check(a, c);
+                check(a, d);
+                check(a, a);
+            });
+        });
+    }
+
+    #[test]
+    fn test_u64() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: u64)| {
+                let (b_wide, truncated) = U::overflowing_from_limbs_slice(&[b]);
+                let (sum, overflow) = a.overflowing_add(b_wide);
+                assert_eq!(a.overflowing_add_u64(b), (sum, overflow || truncated));
+                assert_eq!(a.wrapping_add_u64(b), a.wrapping_add(b_wide));
+                let (diff, overflow) = a.overflowing_sub(b_wide);
+                assert_eq!(a.overflowing_sub_u64(b), (diff, overflow || truncated));
+                assert_eq!(a.wrapping_sub_u64(b), a.wrapping_sub(b_wide));
+                if let Ok(b) = U::try_from(b) {
+                    assert_eq!(a.checked_add_u64(b.to()), a.checked_add(b));
+                    assert_eq!(a.checked_sub_u64(b.to()), a.checked_sub(b));
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_saturating_accumulate() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U)| {
+                let mut acc = a;
+                let saturated = acc.saturating_accumulate(b);
+                assert_eq!(acc, a.saturating_add(b));
+                assert_eq!(saturated, a.checked_add(b).is_none());
+            });
+            let mut acc = U::MAX - U::from(1);
+            assert!(!acc.saturating_accumulate(U::from(1)));
+            assert_eq!(acc, U::MAX);
+            assert!(!acc.saturating_accumulate(U::ZERO));
+            assert_eq!(acc, U::MAX);
+            assert!(acc.saturating_accumulate(U::from(1)));
+            assert_eq!(acc, U::MAX);
+            assert!(acc.saturating_accumulate(U::MAX));
+            assert_eq!(acc, U::MAX);
+        });
+    }
+
+    #[test]
+    fn test_checked_sum() {
+        proptest!(|(values: Vec<u64>)| {
+            let wide = values.iter().map(|&v| u128::from(v)).sum::<u128>();
+            let values = values.into_iter().map(U64::from).collect::<Vec<_>>();
+            assert_eq!(U64::checked_sum(&values), u64::try_from(wide).ok().map(U64::from));
+        });
+        proptest!(|(values: Vec<u8>)| {
+            let values = values.into_iter().map(|v| U64::from(v >> 4)).collect::<Vec<_>>();
+            assert_eq!(U64::checked_sum(&values), Some(values.iter().sum()));
+        });
+        assert_eq!(U64::checked_sum(&[]), Some(U64::ZERO));
+        assert_eq!(U64::checked_sum(&[U64::MAX]), Some(U64::MAX));
+        assert_eq!(U64::checked_sum(&[U64::MAX, U64::from(1)]), None);
+        assert_eq!(U64::checked_sum(&[U64::MAX, U64::ZERO, U64::ZERO]), Some(U64::MAX));
+        assert_eq!(Uint::<0, 0>::checked_sum(&[Uint::ZERO; 3]), Some(Uint::ZERO));
+    }
+
     #[test]
     fn test_associative() {
         const_for!(BITS in SIZES {
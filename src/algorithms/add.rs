@@ -17,3 +17,112 @@ pub fn sbb_n(lhs: &mut [u64], rhs: &[u64], mut borrow: u64) -> u64 {
     }
     borrow
 }
+
+/// Add a little-endian limb slice to another, in place.
+///
+/// Computes `dst += src`, where `src` may be shorter than `dst`. The carry is
+/// propagated through the remaining limbs of `dst`. Returns `true` if the sum
+/// overflows `dst`.
+///
+/// # Panics
+///
+/// Panics if `src` is longer than `dst`.
+///
+/// # Examples
+///
+/// ```
+/// # use ruint::algorithms::add_assign_slice;
+/// let mut acc = [u64::MAX, u64::MAX, 0];
+/// assert!(!add_assign_slice(&mut acc, &[1]));
+/// assert_eq!(acc, [0, 0, 1]);
+/// ```
+#[inline]
+#[track_caller]
+pub fn add_assign_slice(dst: &mut [u64], src: &[u64]) -> bool {
+    assert!(src.len() <= dst.len(), "src is longer than dst");
+    let (low, high) = dst.split_at_mut(src.len());
+    let mut carry = adc_n(low, src, 0);
+    for limb in high {
+        if carry == 0 {
+            break;
+        }
+        (*limb, carry) = adc(*limb, 0, carry);
+    }
+    carry != 0
+}
+
+/// Subtract a little-endian limb slice from another, in place.
+///
+/// Computes `dst -= src`, where `src` may be shorter than `dst`. The borrow is
+/// propagated through the remaining limbs of `dst`. Returns `true` if the
+/// difference underflows, in which case `dst` holds the result modulo
+/// $2^{64 ⋅ n}$.
+///
+/// # Panics
+///
+/// Panics if `src` is longer than `dst`.
+///
+/// # Examples
+///
+/// ```
+/// # use ruint::algorithms::sub_assign_slice;
+/// let mut acc = [0, 0, 1];
+/// assert!(!sub_assign_slice(&mut acc, &[1]));
+/// assert_eq!(acc, [u64::MAX, u64::MAX, 0]);
+/// ```
+#[inline]
+#[track_caller]
+pub fn sub_assign_slice(dst: &mut [u64], src: &[u64]) -> bool {
+    assert!(src.len() <= dst.len(), "src is longer than dst");
+    let (low, high) = dst.split_at_mut(src.len());
+    let mut borrow = sbb_n(low, src, 0);
+    for limb in high {
+        if borrow == 0 {
+            break;
+        }
+        (*limb, borrow) = sbb(*limb, 0, borrow);
+    }
+    borrow != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aliases::{U128, U256};
+    use proptest::proptest;
+
+    #[test]
+    fn test_add_sub_assign_slice() {
+        proptest!(|(a: U256, b: U256, len in 0_usize..=4)| {
+            let b = b & (U256::MAX >> (64 * (4 - len)));
+            let (sum, overflow) = a.overflowing_add(b);
+            let mut limbs = *a.as_limbs();
+            assert_eq!(add_assign_slice(&mut limbs, &b.as_limbs()[..len]), overflow);
+            assert_eq!(limbs, *sum.as_limbs());
+
+            let (difference, underflow) = a.overflowing_sub(b);
+            let mut limbs = *a.as_limbs();
+            assert_eq!(sub_assign_slice(&mut limbs, &b.as_limbs()[..len]), underflow);
+            assert_eq!(limbs, *difference.as_limbs());
+        });
+    }
+
+    #[test]
+    fn test_carry_propagation() {
+        let mut limbs = *U256::MAX.as_limbs();
+        assert!(add_assign_slice(&mut limbs, &[1]));
+        assert_eq!(limbs, [0; 4]);
+        assert!(sub_assign_slice(&mut limbs, &[1]));
+        assert_eq!(limbs, *U256::MAX.as_limbs());
+        let mut limbs = *U128::from(1).as_limbs();
+        assert!(!add_assign_slice(&mut limbs, &[]));
+        assert!(!sub_assign_slice(&mut limbs, &[1, 0]));
+        assert_eq!(limbs, [0; 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "src is longer than dst")]
+    fn test_add_assign_slice_longer() {
+        let _ = add_assign_slice(&mut [0], &[0, 0]);
+    }
+}
@@ -23,7 +23,7 @@ pub use self::{
     reciprocal::{reciprocal, reciprocal_2, reciprocal_2_mg10, reciprocal_mg10, reciprocal_ref},
     small::{
         div_2x1, div_2x1_mg10, div_2x1_ref, div_3x2, div_3x2_mg10, div_3x2_ref, div_nx1,
-        div_nx1_normalized, div_nx2, div_nx2_normalized,
+        div_nx1_normalized, div_nx2, div_nx2_normalized, rem_nx1, rem_nx2,
     },
 };
 use crate::algorithms::DoubleWord;
@@ -98,6 +98,64 @@ pub fn div(numerator: &mut [u64], divisor: &mut [u64]) {
     }
 }
 
+/// ⚠️ Remainder of a division.
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// The remainder is stored in the `divisor`. The `numerator` is used as
+/// scratch space and its contents are unspecified afterwards.
+///
+/// # Algorithm
+///
+/// Divisors of one or two limbs use [`rem_nx1`] and [`rem_nx2`], which do not
+/// store the quotient. Larger divisors fall back to [`div_nxm`].
+///
+/// # Panics
+///
+/// Panics if `divisor` is zero.
+#[inline]
+pub fn rem(numerator: &mut [u64], divisor: &mut [u64]) {
+    // Trim most significant zeros from divisor.
+    let i = divisor
+        .iter()
+        .rposition(|&x| x != 0)
+        .expect("Divisor is zero");
+    let divisor = &mut divisor[..=i];
+    debug_assert!(!divisor.is_empty());
+    debug_assert!(divisor.last() != Some(&0));
+
+    // Small divisors do not need to trim the numerator.
+    match divisor.len() {
+        1 => {
+            divisor[0] = rem_nx1(numerator, divisor[0]);
+            return;
+        }
+        2 => {
+            let remainder = rem_nx2(numerator, u128::join(divisor[1], divisor[0]));
+            divisor[0] = remainder.low();
+            divisor[1] = remainder.high();
+            return;
+        }
+        _ => {}
+    }
+
+    // Trim zeros from numerator
+    let Some(i) = numerator.iter().rposition(|&n| n != 0) else {
+        divisor.fill(0);
+        return;
+    };
+    let numerator = &mut numerator[..=i];
+
+    // If numerator is smaller than divisor r = numerator
+    if numerator.len() < divisor.len() {
+        let (remainder, padding) = divisor.split_at_mut(numerator.len());
+        remainder.copy_from_slice(numerator);
+        padding.fill(0);
+        return;
+    }
+    div_nxm(numerator, divisor);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -384,9 +442,12 @@ mod tests {
                 let mut divisor = <$d>::from(divisor).into_limbs();
                 let quotient = <$n>::from(quotient).into_limbs();
                 let remainder = <$d>::from(remainder).into_limbs();
+                let (mut scratch, mut rem_divisor) = (numerator, divisor);
                 div(&mut numerator, &mut divisor);
                 assert_eq!(numerator, quotient);
                 assert_eq!(divisor, remainder);
+                rem(&mut scratch, &mut rem_divisor);
+                assert_eq!(rem_divisor, remainder);
             }
         };
     }
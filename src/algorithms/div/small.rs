@@ -87,6 +87,44 @@ pub fn div_nx1(limbs: &mut [u64], divisor: u64) -> u64 {
     remainder >> shift
 }
 
+/// ⚠️ Compute the remainder of a single limb division.
+///
+/// Like [`div_nx1`] but does not write the quotient, so `limbs` is not
+/// modified and may have leading zeros.
+///
+/// # Panics
+///
+/// May panic if `divisor` is zero.
+#[inline]
+#[must_use]
+pub fn rem_nx1(limbs: &[u64], divisor: u64) -> u64 {
+    debug_assert!(divisor != 0);
+
+    // Normalize and compute reciprocal
+    let shift = divisor.leading_zeros();
+    let divisor = divisor << shift;
+    let reciprocal = reciprocal(divisor);
+    let Some((&last, _)) = limbs.split_last() else {
+        return 0;
+    };
+    if shift == 0 {
+        return limbs.iter().rev().fold(0, |remainder, &u| {
+            div_2x1(u128::join(remainder, u), divisor, reciprocal).1
+        });
+    }
+
+    let mut remainder = last >> (64 - shift);
+    for window in limbs.windows(2).rev() {
+        let u = (window[1] << shift) | (window[0] >> (64 - shift));
+        remainder = div_2x1(u128::join(remainder, u), divisor, reciprocal).1;
+    }
+    let n = u128::join(remainder, limbs[0] << shift);
+    let (_, remainder) = div_2x1(n, divisor, reciprocal);
+
+    // Un-normalize remainder
+    remainder >> shift
+}
+
 /// ⚠️ Compute double limb normalized division.
 ///
 /// Requires `divisor` to be in the range $[2^{127}, 2^{128})$ (i.e.
@@ -153,6 +191,43 @@ pub fn div_nx2(limbs: &mut [u64], divisor: u128) -> u128 {
     remainder >> shift
 }
 
+/// ⚠️ Compute the remainder of a double limb division.
+///
+/// Like [`div_nx2`] but does not write the quotient, so `limbs` is not
+/// modified and may have leading zeros.
+///
+/// # Panics
+///
+/// May panic if `divisor` is less than $2^{64}$.
+#[inline]
+#[must_use]
+pub fn rem_nx2(limbs: &[u64], divisor: u128) -> u128 {
+    debug_assert!(divisor >= 1 << 64);
+
+    // Normalize and compute reciprocal
+    let shift = divisor.high().leading_zeros();
+    let divisor = divisor << shift;
+    let reciprocal = reciprocal_2(divisor);
+    let Some((&last, _)) = limbs.split_last() else {
+        return 0;
+    };
+    if shift == 0 {
+        return limbs.iter().rev().fold(0, |remainder, &u| {
+            div_3x2(remainder, u, divisor, reciprocal).1
+        });
+    }
+
+    let mut remainder = u128::from(last >> (64 - shift));
+    for window in limbs.windows(2).rev() {
+        let u = (window[1] << shift) | (window[0] >> (64 - shift));
+        remainder = div_3x2(remainder, u, divisor, reciprocal).1;
+    }
+    let (_, remainder) = div_3x2(remainder, limbs[0] << shift, divisor, reciprocal);
+
+    // Un-normalize remainder
+    remainder >> shift
+}
+
 #[inline]
 #[must_use]
 pub fn div_2x1_ref(u: u128, d: u64) -> (u64, u64) {
@@ -297,7 +372,35 @@ mod tests {
         });
     }
 
-    #[ignore] // TODO
+    #[test]
+    fn test_rem_nx1() {
+        proptest!(|(limbs in collection::vec(u64::ANY, 0..6), d: u64, shift in 0..64_u32)| {
+            let d = (d >> shift).max(1);
+            let remainder = rem_nx1(&limbs, d);
+            let expected = limbs.iter().rev().fold(0_u128, |r, &u| {
+                ((r << 64) | u128::from(u)) % u128::from(d)
+            });
+            assert_eq!(u128::from(remainder), expected);
+            if limbs.last().map_or(false, |&u| u != 0) {
+                let mut quotient = limbs;
+                assert_eq!(div_nx1(&mut quotient, d), remainder);
+            }
+        });
+    }
+
+    #[test]
+    fn test_rem_nx2() {
+        proptest!(|(limbs in collection::vec(u64::ANY, 0..6), d: u128, shift in 0..64_u32)| {
+            let d = (d >> shift) | (1 << 64);
+            let remainder = rem_nx2(&limbs, d);
+            let end = limbs.iter().rposition(|&u| u != 0).map_or(0, |i| i + 1);
+            let mut quotient = limbs[..end].to_vec();
+            let expected = if quotient.is_empty() { 0 } else { div_nx2(&mut quotient, d) };
+            assert_eq!(remainder, expected);
+        });
+    }
+
+    #[ignore = "TODO"]
     #[test]
     fn test_div_3x2_ref() {
         proptest!(|(q: u64, r: u128, mut d: u128)| {
@@ -9,14 +9,37 @@ pub use self::matrix::Matrix as LehmerMatrix;
 use crate::Uint;
 use core::mem::swap;
 
-/// ⚠️ Lehmer's GCD algorithms.
+/// ⚠️ Greatest common divisor.
 ///
-/// **Warning.** This struct is not part of the stable API.
+/// **Warning.** This function is not part of the stable API.
 ///
 /// See [`gcd_extended`] for documentation.
+///
+/// Dispatches to [`gcd_binary`] for single limb sizes, where it is faster,
+/// and to [`gcd_lehmer`] otherwise. From two limbs on the cost of the binary
+/// algorithm grows quadratically and Lehmer's algorithm wins (see the
+/// `algorithms/gcd` benchmarks).
 #[inline]
 #[must_use]
 pub fn gcd<const BITS: usize, const LIMBS: usize>(
+    a: Uint<BITS, LIMBS>,
+    b: Uint<BITS, LIMBS>,
+) -> Uint<BITS, LIMBS> {
+    if LIMBS <= 1 {
+        gcd_binary(a, b)
+    } else {
+        gcd_lehmer(a, b)
+    }
+}
+
+/// ⚠️ Lehmer's GCD algorithm.
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// See [`gcd_extended`] for documentation.
+#[inline]
+#[must_use]
+pub fn gcd_lehmer<const BITS: usize, const LIMBS: usize>(
     mut a: Uint<BITS, LIMBS>,
     mut b: Uint<BITS, LIMBS>,
 ) -> Uint<BITS, LIMBS> {
@@ -39,6 +62,40 @@ pub fn gcd<const BITS: usize, const LIMBS: usize>(
     a
 }
 
+/// ⚠️ Binary GCD (Stein's algorithm).
+///
+/// **Warning.** This function is not part of the stable API.
+///
+/// Uses only shifts and subtractions, which for small sizes is faster than the
+/// divisions required by [`gcd_lehmer`]. Quadratic in `BITS`.
+#[inline]
+#[must_use]
+pub fn gcd_binary<const BITS: usize, const LIMBS: usize>(
+    mut a: Uint<BITS, LIMBS>,
+    mut b: Uint<BITS, LIMBS>,
+) -> Uint<BITS, LIMBS> {
+    if a == Uint::ZERO {
+        return b;
+    }
+    if b == Uint::ZERO {
+        return a;
+    }
+    // gcd(2^i a, 2^j b) = 2^min(i,j) gcd(a, b)
+    let shift = (a | b).trailing_zeros();
+    a >>= a.trailing_zeros();
+    loop {
+        // Invariant: `a` is odd and `b` is non-zero.
+        b >>= b.trailing_zeros();
+        if a > b {
+            swap(&mut a, &mut b);
+        }
+        b -= a;
+        if b == Uint::ZERO {
+            return a << shift;
+        }
+    }
+}
+
 /// ⚠️ Lehmer's extended GCD.
 ///
 /// **Warning.** This struct is not part of the stable API.
@@ -231,6 +288,43 @@ mod tests {
         });
     }
 
+    #[test]
+    #[allow(clippy::absurd_extreme_comparisons)] // Generated code
+    fn test_gcd_binary() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let (zero, one) = (U::ZERO, U::from(1));
+            assert_eq!(gcd_binary(zero, zero), zero);
+            assert_eq!(gcd_binary(zero, U::MAX), U::MAX);
+            assert_eq!(gcd_binary(U::MAX, zero), U::MAX);
+            assert_eq!(gcd_binary(U::MAX, U::MAX), U::MAX);
+            assert_eq!(gcd_binary(U::MAX, one), one);
+            for i in 0..BITS {
+                let x = one << i;
+                assert_eq!(gcd_binary(x, zero), x);
+                assert_eq!(gcd_binary(x, U::MAX), one);
+                for j in [0, i / 2, i] {
+                    assert_eq!(gcd_binary(x, one << j), one << j);
+                }
+                // Many shared factors of two around an odd core.
+                let y = x | (x << 1);
+                assert_eq!(gcd_binary(y, x), x);
+                assert_eq!(gcd_binary(y, y), y);
+            }
+
+            // The binary algorithm is the default for single limb sizes.
+            let cases = if BITS <= 64 { Config::default().cases } else { 10 };
+            let config = Config { cases, ..Default::default() };
+            proptest!(config, |(a: U, b: U, shift in 0..BITS)| {
+                assert_eq!(gcd_binary(a, b), gcd_lehmer(a, b));
+                let (a, b) = (a << shift, b << shift);
+                assert_eq!(gcd_binary(a, b), gcd_lehmer(a, b));
+            });
+        });
+        assert_eq!(gcd_binary(Uint::<0, 0>::ZERO, Uint::ZERO), Uint::ZERO);
+    }
+
     #[test]
     fn test_gcd_extended() {
         const_for!(BITS in SIZES {
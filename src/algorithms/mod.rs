@@ -16,13 +16,13 @@ mod ops;
 mod shift;
 
 pub use self::{
-    add::{adc_n, sbb_n},
-    div::div,
-    gcd::{gcd, gcd_extended, inv_mod, LehmerMatrix},
-    mul::{add_nx1, addmul, addmul_n, addmul_nx1, mul_nx1, submul_nx1},
+    add::{adc_n, add_assign_slice, sbb_n, sub_assign_slice},
+    div::{div, rem},
+    gcd::{gcd, gcd_binary, gcd_extended, gcd_lehmer, inv_mod, LehmerMatrix},
+    mul::{add_nx1, addmul, addmul_n, addmul_nx1, mul_add_slice, mul_nx1, sqr, submul_nx1},
     mul_redc::{mul_redc, square_redc},
     ops::{adc, sbb},
-    shift::{shift_left_small, shift_right_small},
+    shift::{shift_left, shift_left_small, shift_right, shift_right_small},
 };
 
 trait DoubleWord<T>: Sized + Copy {
@@ -1,6 +1,6 @@
 #![allow(clippy::module_name_repetitions)]
 
-use crate::algorithms::{ops::sbb, DoubleWord};
+use crate::algorithms::{carrying_add, ops::sbb, shift::shift_left_small, DoubleWord};
 
 /// ⚠️ Computes `result += a * b` and checks for overflow.
 ///
@@ -75,6 +75,110 @@ pub fn addmul(mut lhs: &mut [u64], mut a: &[u64], mut b: &[u64]) -> bool {
     overflow
 }
 
+/// Multiply-accumulate little-endian limb slices, in place.
+///
+/// Computes `acc += a * b`, truncated to the length of `acc`. Returns `true`
+/// if the result does not fit in `acc`. The slices may have any length and
+/// leading or trailing zeros.
+///
+/// This is the schoolbook multiplication used throughout the crate. To get
+/// the full product make `acc` at least `a.len() + b.len()` limbs.
+///
+/// # Examples
+///
+/// ```
+/// # use ruint::algorithms::mul_add_slice;
+/// let mut acc = [1, 0, 0];
+/// assert!(!mul_add_slice(&mut acc, &[u64::MAX], &[u64::MAX, 1]));
+/// // 1 + (2^64 - 1) * (2^65 - 1) = 2^129 - 2^65 - 2^64 + 2
+/// assert_eq!(acc, [2, u64::MAX - 2, 1]);
+///
+/// let mut acc = [0];
+/// assert!(mul_add_slice(&mut acc, &[1 << 32], &[1 << 32]));
+/// assert_eq!(acc, [0]);
+/// ```
+#[inline]
+pub fn mul_add_slice(acc: &mut [u64], a: &[u64], b: &[u64]) -> bool {
+    addmul(acc, a, b)
+}
+
+/// Computes `result = a * a` and checks for overflow.
+///
+/// Arrays are in little-endian order. Both arrays can be arbitrary sized, the
+/// square is truncated to the length of `result`.
+///
+/// # Algorithm
+///
+/// Schoolbook squaring. The cross terms $a_i ⋅ a_j$ for $i < j$ are computed
+/// once and doubled with a shift, after which the diagonal terms $a_i^2$ are
+/// added. This takes about half the limb multiplications of [`addmul`].
+///
+/// Overflow follows from the bit length of `a` alone: if `a` has $b$ bits then
+/// $a^2$ has $2b - 1$ or $2b$ bits, and since the capacity of `result` is even
+/// the square fits exactly when $2b$ does.
+///
+/// # Examples
+///
+/// ```
+/// # use ruint::algorithms::sqr;
+/// let mut result = [0; 2];
+/// assert!(!sqr(&mut result, &[u64::MAX]));
+/// assert_eq!(result, [1, u64::MAX - 1]);
+///
+/// let mut result = [0; 1];
+/// assert!(sqr(&mut result, &[1 << 32]));
+/// assert_eq!(result, [0]);
+/// ```
+#[inline]
+pub fn sqr(result: &mut [u64], mut a: &[u64]) -> bool {
+    result.fill(0);
+
+    // Trim zeros from `a`
+    while let [rest @ .., 0] = a {
+        a = rest;
+    }
+    let Some(&top) = a.last() else {
+        return false;
+    };
+    let bits = 64 * a.len() - top.leading_zeros() as usize;
+    let overflow = bits > 32 * result.len();
+
+    // Limbs beyond the length of `result` only contribute to truncated terms.
+    let a = &a[..a.len().min(result.len())];
+
+    // Add cross terms `a[i] * a[j]` for `i < j`.
+    for (i, &b) in a.iter().enumerate() {
+        let start = 2 * i + 1;
+        if start >= result.len() {
+            break;
+        }
+        let len = (a.len() - i - 1).min(result.len() - start);
+        let (target, rest) = result[start..].split_at_mut(len);
+        let carry = addmul_nx1(target, &a[i + 1..i + 1 + len], b);
+        add_nx1(rest, carry);
+    }
+
+    // Double the cross terms.
+    shift_left_small(result, 1);
+
+    // Add diagonal terms `a[i] * a[i]`.
+    let mut carry = false;
+    for (i, &a) in a.iter().enumerate() {
+        let (low, high) = u128::mul(a, a).split();
+        if let Some(limb) = result.get_mut(2 * i) {
+            (*limb, carry) = carrying_add(*limb, low, carry);
+        }
+        if let Some(limb) = result.get_mut(2 * i + 1) {
+            (*limb, carry) = carrying_add(*limb, high, carry);
+        }
+    }
+    if let Some(rest) = result.get_mut(2 * a.len()..) {
+        add_nx1(rest, u64::from(carry));
+    }
+
+    overflow
+}
+
 /// Computes `lhs += a` and returns the carry.
 #[inline(always)]
 pub fn add_nx1(lhs: &mut [u64], mut a: u64) -> u64 {
@@ -238,6 +342,7 @@ pub fn submul_nx1(lhs: &mut [u64], a: &[u64], b: u64) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aliases::{U128, U256};
     use proptest::{collection, num::u64, proptest};
 
     #[allow(clippy::cast_possible_truncation)] // Intentional truncation.
@@ -293,6 +398,35 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_mul_add_slice() {
+        let any_vec = collection::vec(u64::ANY, 0..10);
+        proptest!(|(mut acc in &any_vec, a in &any_vec, b in &any_vec)| {
+            let mut ref_acc = acc.clone();
+            let ref_overflow = addmul_ref(&mut ref_acc, &a, &b);
+            assert_eq!(mul_add_slice(&mut acc, &a, &b), ref_overflow);
+            assert_eq!(acc, ref_acc);
+        });
+        proptest!(|(acc: U256, a: U128, b: U128)| {
+            let mut limbs = acc.into_limbs();
+            let overflow = mul_add_slice(&mut limbs, a.as_limbs(), b.as_limbs());
+            let expected = acc.overflowing_add(a.widening_mul(b));
+            assert_eq!((U256::from_limbs(limbs), overflow), expected);
+        });
+    }
+
+    #[test]
+    fn test_sqr() {
+        let any_vec = collection::vec(u64::ANY, 0..10);
+        proptest!(|(a in &any_vec, len in 0_usize..20)| {
+            let mut result = vec![0; len];
+            let mut expected = vec![0; len];
+            let expected_overflow = addmul_ref(&mut expected, &a, &a);
+            assert_eq!(sqr(&mut result, &a), expected_overflow);
+            assert_eq!(result, expected);
+        });
+    }
+
     fn test_vals(lhs: &[u64], rhs: &[u64], expected: &[u64], expected_overflow: bool) {
         let mut result = vec![0; expected.len()];
         let overflow = addmul(&mut result, lhs, rhs);
@@ -23,9 +23,77 @@ pub fn shift_right_small(limbs: &mut [u64], amount: usize) -> u64 {
     overflow
 }
 
+/// Shift a little-endian limb slice left by `bits`, in place.
+///
+/// Computes $\mod{\mathtt{limbs} ⋅ 2^{\mathtt{bits}}}_{2^{64 ⋅ n}}$ where $n$
+/// is the length of `limbs`. Shifts of `64` or more move whole limbs, and
+/// shifts beyond the length of the slice clear it. Returns `true` if any
+/// non-zero bits were shifted out.
+///
+/// # Examples
+///
+/// ```
+/// # use ruint::algorithms::shift_left;
+/// let mut limbs = [1, 2, 0];
+/// assert!(!shift_left(&mut limbs, 64 + 4));
+/// assert_eq!(limbs, [0, 1 << 4, 2 << 4]);
+/// ```
+#[inline]
+pub fn shift_left(limbs: &mut [u64], bits: usize) -> bool {
+    let (words, bits) = (bits / 64, bits % 64);
+    if words >= limbs.len() {
+        let overflow = limbs.iter().any(|&limb| limb != 0);
+        limbs.fill(0);
+        return overflow;
+    }
+    let keep = limbs.len() - words;
+    let mut overflow = limbs[keep..].iter().any(|&limb| limb != 0);
+    limbs.copy_within(..keep, words);
+    limbs[..words].fill(0);
+    if bits != 0 {
+        overflow |= shift_left_small(limbs, bits) != 0;
+    }
+    overflow
+}
+
+/// Shift a little-endian limb slice right by `bits`, in place.
+///
+/// Computes $\floor{\mathtt{limbs} / 2^{\mathtt{bits}}}$. Shifts of `64` or
+/// more move whole limbs, and shifts beyond the length of the slice clear it.
+/// Returns `true` if any non-zero bits were shifted out, i.e. if the division
+/// was not exact.
+///
+/// # Examples
+///
+/// ```
+/// # use ruint::algorithms::shift_right;
+/// let mut limbs = [1, 2, 3];
+/// assert!(shift_right(&mut limbs, 64 + 1));
+/// assert_eq!(limbs, [1 | (1 << 63), 1, 0]);
+/// ```
+#[inline]
+pub fn shift_right(limbs: &mut [u64], bits: usize) -> bool {
+    let (words, bits) = (bits / 64, bits % 64);
+    if words >= limbs.len() {
+        let overflow = limbs.iter().any(|&limb| limb != 0);
+        limbs.fill(0);
+        return overflow;
+    }
+    let keep = limbs.len() - words;
+    let mut overflow = limbs[..words].iter().any(|&limb| limb != 0);
+    limbs.copy_within(words.., 0);
+    limbs[keep..].fill(0);
+    if bits != 0 {
+        overflow |= shift_right_small(&mut limbs[..keep], bits) != 0;
+    }
+    overflow
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aliases::U256;
+    use proptest::proptest;
 
     #[test]
     fn test_shift_left_small() {
@@ -42,4 +110,43 @@ mod tests {
         assert_eq!(limbs, [0x0123_4567_89ab_cdef, 0x0123_4567_89ab_cdef]);
         assert_eq!(overflow, 0xf << 60);
     }
+
+    #[test]
+    fn test_shift_zero() {
+        let mut limbs = [0x1234_5678_9abc_def0, u64::MAX];
+        assert!(!shift_left(&mut limbs, 0));
+        assert!(!shift_right(&mut limbs, 0));
+        assert_eq!(limbs, [0x1234_5678_9abc_def0, u64::MAX]);
+        let mut empty: [u64; 0] = [];
+        assert!(!shift_left(&mut empty, 100));
+        assert!(!shift_right(&mut empty, 100));
+    }
+
+    #[test]
+    fn test_shift_cross_limb() {
+        let mut limbs = [u64::MAX, 0, 0];
+        assert!(!shift_left(&mut limbs, 100));
+        assert_eq!(limbs, [0, u64::MAX << 36, u64::MAX >> 28]);
+        assert!(!shift_right(&mut limbs, 100));
+        assert_eq!(limbs, [u64::MAX, 0, 0]);
+        assert!(shift_left(&mut limbs, 129));
+        assert_eq!(limbs, [0, 0, u64::MAX << 1]);
+        assert!(shift_right(&mut limbs, 192));
+        assert_eq!(limbs, [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_shift_uint() {
+        proptest!(|(value: U256, bits in 0_usize..300)| {
+            let mut limbs = *value.as_limbs();
+            let overflow = shift_left(&mut limbs, bits);
+            assert_eq!(limbs, *(value << bits).as_limbs());
+            assert_eq!(overflow, !value.is_zero() && value.leading_zeros() < bits);
+
+            let mut limbs = *value.as_limbs();
+            let overflow = shift_right(&mut limbs, bits);
+            assert_eq!(limbs, *(value >> bits).as_limbs());
+            assert_eq!(overflow, !value.is_zero() && value.trailing_zeros() < bits);
+        });
+    }
 }
@@ -13,12 +13,21 @@ pub type U8 = Uint<8, 1>;
 /// [`Uint`] for `16` bits. Similar to [`u16`].
 pub type U16 = Uint<16, 1>;
 
+/// [`Uint`] for `24` bits.
+pub type U24 = Uint<24, 1>;
+
 /// [`Uint`] for `32` bits. Similar to [`u32`].
 pub type U32 = Uint<32, 1>;
 
+/// [`Uint`] for `48` bits.
+pub type U48 = Uint<48, 1>;
+
 /// [`Uint`] for `64` bits. Similar to [`u64`].
 pub type U64 = Uint<64, 1>;
 
+/// [`Uint`] for `96` bits.
+pub type U96 = Uint<96, 2>;
+
 /// [`Uint`] for `128` bits. Similar to [`u128`].
 pub type U128 = Uint<128, 2>;
 
@@ -34,8 +43,11 @@ bit_alias! {
     B1(1, 1);
     B8(8, 1);
     B16(16, 1);
+    B24(24, 1);
     B32(32, 1);
+    B48(48, 1);
     B64(64, 1);
+    B96(96, 2);
     B128(128, 2);
 }
 
@@ -74,8 +86,11 @@ mod tests {
         let _ = (U1::ZERO, U1::MAX, B1::ZERO);
         let _ = (U8::ZERO, U8::MAX, B8::ZERO);
         let _ = (U16::ZERO, U16::MAX, B16::ZERO);
+        let _ = (U24::ZERO, U24::MAX, B24::ZERO);
         let _ = (U32::ZERO, U32::MAX, B32::ZERO);
+        let _ = (U48::ZERO, U48::MAX, B48::ZERO);
         let _ = (U64::ZERO, U64::MAX, B64::ZERO);
+        let _ = (U96::ZERO, U96::MAX, B96::ZERO);
         let _ = (U128::ZERO, U128::MAX, B128::ZERO);
         let _ = (U160::ZERO, U160::MAX, B160::ZERO);
         let _ = (U192::ZERO, U192::MAX, B192::ZERO);
@@ -37,6 +37,44 @@ impl fmt::Display for BaseConvertError {
     }
 }
 
+/// Error for [`from_base_be_bounded`][Uint::from_base_be_bounded].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BoundedBaseConvertError {
+    /// More than the allowed `.0` digits were provided.
+    TooManyDigits(usize),
+
+    /// Error from [`Uint::from_base_be`] for the digits within the bound.
+    BaseConvertError(BaseConvertError),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BoundedBaseConvertError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::BaseConvertError(e) => Some(e),
+            Self::TooManyDigits(_) => None,
+        }
+    }
+}
+
+impl From<BaseConvertError> for BoundedBaseConvertError {
+    #[inline]
+    fn from(value: BaseConvertError) -> Self {
+        Self::BaseConvertError(value)
+    }
+}
+
+impl fmt::Display for BoundedBaseConvertError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooManyDigits(max) => write!(f, "more than {max} digits"),
+            Self::BaseConvertError(e) => e.fmt(f),
+        }
+    }
+}
+
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Returns an iterator over the base `base` digits of the number in
     /// little-endian order.
@@ -64,12 +102,16 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// power of `10` that still fits `u64`. This way much fewer iterations
     /// are required to extract all the digits.
     ///
+    /// The digits are computed up front, so the iterator knows its length and
+    /// [`ExactSizeIterator::len`] can be used to pre-allocate output buffers.
+    ///
     /// # Panics
     ///
     /// Panics if the base is less than 2.
     #[inline]
+    #[must_use]
     #[cfg(feature = "alloc")] // OPT: Find an allocation free method. Maybe extract from the top?
-    pub fn to_base_be(&self, base: u64) -> impl Iterator<Item = u64> {
+    pub fn to_base_be(&self, base: u64) -> impl ExactSizeIterator<Item = u64> {
         struct OwnedVecIterator {
             vec: alloc::vec::Vec<u64>,
         }
@@ -81,8 +123,15 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
             fn next(&mut self) -> Option<Self::Item> {
                 self.vec.pop()
             }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.vec.len(), Some(self.vec.len()))
+            }
         }
 
+        impl ExactSizeIterator for OwnedVecIterator {}
+
         assert!(base > 1);
         OwnedVecIterator {
             vec: self.to_base_le(base).collect(),
@@ -91,6 +140,9 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
 
     /// Constructs the [`Uint`] from digits in the base `base` in little-endian.
     ///
+    /// All digits are read, so `digits` must be finite. Use
+    /// [`Self::from_base_le_checked`] to stop early.
+    ///
     /// # Errors
     ///
     /// * [`BaseConvertError::InvalidBase`] if the base is less than 2.
@@ -98,6 +150,49 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// * [`BaseConvertError::Overflow`] if the number is too large to fit.
     #[inline]
     pub fn from_base_le<I>(base: u64, digits: I) -> Result<Self, BaseConvertError>
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        let mut iter = digits.into_iter();
+        let (result, _) = Self::from_base_le_checked(base, iter.by_ref())?;
+        for digit in iter {
+            if digit >= base {
+                return Err(BaseConvertError::InvalidDigit(digit, base));
+            }
+            if digit != 0 {
+                return Err(BaseConvertError::Overflow);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Constructs the [`Uint`] from digits in the base `base` in little-endian,
+    /// reading only as many digits as can contribute to the value.
+    ///
+    /// Returns the value and the number of digits consumed. Reading stops as
+    /// soon as the next digit's place value no longer fits in `BITS`, so any
+    /// further digit must be zero for the number to fit. The remaining digits
+    /// are left in the iterator, pass `iter.by_ref()` to inspect them. This
+    /// terminates on infinite iterators.
+    ///
+    /// # Errors
+    ///
+    /// * [`BaseConvertError::InvalidBase`] if the base is less than 2.
+    /// * [`BaseConvertError::InvalidDigit`] if a consumed digit is out of
+    ///   range.
+    /// * [`BaseConvertError::Overflow`] if the consumed digits do not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U8;
+    /// let mut digits = [5, 2, 2, 0, 0, 0].into_iter().chain(core::iter::repeat(0));
+    /// let (value, consumed) = U8::from_base_le_checked(10, digits.by_ref()).unwrap();
+    /// assert_eq!((value, consumed), (U8::from(225), 3));
+    /// assert_eq!(digits.next(), Some(0));
+    /// ```
+    #[inline]
+    pub fn from_base_le_checked<I>(base: u64, digits: I) -> Result<(Self, usize), BaseConvertError>
     where
         I: IntoIterator<Item = u64>,
     {
@@ -105,21 +200,15 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
             return Err(BaseConvertError::InvalidBase(base));
         }
         if BITS == 0 {
-            for digit in digits {
-                if digit >= base {
-                    return Err(BaseConvertError::InvalidDigit(digit, base));
-                }
-                if digit != 0 {
-                    return Err(BaseConvertError::Overflow);
-                }
-            }
-            return Ok(Self::ZERO);
+            // No digit position fits.
+            return Ok((Self::ZERO, 0));
         }
 
-        let mut iter = digits.into_iter();
         let mut result = Self::ZERO;
         let mut power = Self::from(1);
-        for digit in iter.by_ref() {
+        let mut consumed = 0;
+        for digit in digits {
+            consumed += 1;
             if digit >= base {
                 return Err(BaseConvertError::InvalidDigit(digit, base));
             }
@@ -137,15 +226,7 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
                 break;
             }
         }
-        for digit in iter {
-            if digit >= base {
-                return Err(BaseConvertError::InvalidDigit(digit, base));
-            }
-            if digit != 0 {
-                return Err(BaseConvertError::Overflow);
-            }
-        }
-        Ok(result)
+        Ok((result, consumed))
     }
 
     /// Constructs the [`Uint`] from digits in the base `base` in big-endian.
@@ -189,6 +270,82 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
 
         Ok(result)
     }
+
+    /// Constructs the [`Uint`] from at most `max_digits` digits in the base
+    /// `base` in big-endian.
+    ///
+    /// Unlike [`Self::from_base_be`], which accepts any number of leading
+    /// zeros, this bounds the work done on untrusted input. It fails before
+    /// reading any digit if the iterator's [`Iterator::size_hint`] already
+    /// exceeds `max_digits`, and otherwise as soon as digit `max_digits + 1`
+    /// is reached.
+    ///
+    /// # Errors
+    ///
+    /// * [`BoundedBaseConvertError::TooManyDigits`] if there are more than
+    ///   `max_digits` digits.
+    /// * [`BoundedBaseConvertError::BaseConvertError`] if the base is less
+    ///   than 2, a digit is out of range or the number is too large to fit,
+    ///   see [`Self::from_base_be`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{aliases::U64, BoundedBaseConvertError};
+    /// assert_eq!(U64::from_base_be_bounded(10, [1, 2, 3], 20), Ok(U64::from(123)));
+    /// assert_eq!(
+    ///     U64::from_base_be_bounded(10, core::iter::repeat(0), 20),
+    ///     Err(BoundedBaseConvertError::TooManyDigits(20))
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_base_be_bounded<I: IntoIterator<Item = u64>>(
+        base: u64,
+        digits: I,
+        max_digits: usize,
+    ) -> Result<Self, BoundedBaseConvertError> {
+        if base < 2 {
+            return Err(BaseConvertError::InvalidBase(base).into());
+        }
+        let mut iter = digits.into_iter();
+        if iter.size_hint().0 > max_digits {
+            return Err(BoundedBaseConvertError::TooManyDigits(max_digits));
+        }
+        let result = Self::from_base_be(base, iter.by_ref().take(max_digits))?;
+        if iter.next().is_some() {
+            return Err(BoundedBaseConvertError::TooManyDigits(max_digits));
+        }
+        Ok(result)
+    }
+
+    /// Constructs the [`Uint`] from byte digits in the base `base` in
+    /// big-endian.
+    ///
+    /// This is [`Self::from_base_be`] for the common case of digits stored as
+    /// bytes. Base 256 decodes the raw bytes directly.
+    ///
+    /// # Errors
+    ///
+    /// * [`BaseConvertError::InvalidBase`] if the base is less than 2.
+    /// * [`BaseConvertError::InvalidDigit`] if a digit is out of range.
+    /// * [`BaseConvertError::Overflow`] if the number is too large to fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U64;
+    /// assert_eq!(U64::from_base_be_bytes(10, &[1, 2, 3]), Ok(U64::from(123)));
+    /// assert_eq!(U64::from_base_be_bytes(256, &[1, 0]), Ok(U64::from(256)));
+    /// ```
+    #[inline]
+    pub fn from_base_be_bytes(base: u64, digits: &[u8]) -> Result<Self, BaseConvertError> {
+        if base == 256 {
+            // Leading zeros are allowed like in `from_base_be`.
+            let start = digits.iter().position(|&d| d != 0).unwrap_or(digits.len());
+            return Self::try_from_be_slice(&digits[start..]).ok_or(BaseConvertError::Overflow);
+        }
+        Self::from_base_be(base, digits.iter().copied().map(u64::from))
+    }
 }
 
 struct SpigotLittle<const LIMBS: usize> {
@@ -254,6 +411,110 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_base_be_bytes() {
+        type U = Uint<64, 1>;
+        assert_eq!(
+            U::from_base_be_bytes(10, &[1, 2, 3, 4, 5, 6, 7, 8, 9]),
+            Ok(U::from(123456789))
+        );
+        assert_eq!(
+            U::from_base_be_bytes(10, &[1, 10]),
+            Err(BaseConvertError::InvalidDigit(10, 10))
+        );
+        assert_eq!(
+            U::from_base_be_bytes(1, &[0]),
+            Err(BaseConvertError::InvalidBase(1))
+        );
+        let bytes = N.to_be_bytes::<32>();
+        assert_eq!(Uint::<256, 4>::from_base_be_bytes(256, &bytes), Ok(N));
+        assert_eq!(
+            Uint::<256, 4>::from_base_be_bytes(256, &[&[0; 8], &bytes[..]].concat()),
+            Ok(N)
+        );
+        assert_eq!(
+            U::from_base_be_bytes(256, &bytes),
+            Err(BaseConvertError::Overflow)
+        );
+        assert_eq!(U::from_base_be_bytes(256, &[]), Ok(U::ZERO));
+        let digits = N
+            .to_base_be(10)
+            .map(|d| d.try_into().unwrap())
+            .collect::<Vec<u8>>();
+        assert_eq!(Uint::<256, 4>::from_base_be_bytes(10, &digits), Ok(N));
+    }
+
+    #[test]
+    fn test_from_base_be_bounded() {
+        type U = Uint<64, 1>;
+        let digits = N.to_base_be(10).collect::<Vec<_>>();
+        assert_eq!(digits.len(), 77);
+        assert_eq!(
+            Uint::<256, 4>::from_base_be_bounded(10, digits.iter().copied(), 78),
+            Ok(N)
+        );
+        assert_eq!(
+            Uint::<256, 4>::from_base_be_bounded(10, digits.iter().copied(), 77),
+            Ok(N)
+        );
+        assert_eq!(
+            Uint::<256, 4>::from_base_be_bounded(10, digits.iter().copied(), 76),
+            Err(BoundedBaseConvertError::TooManyDigits(76))
+        );
+        assert_eq!(U::from_base_be_bounded(10, [], 0), Ok(U::ZERO));
+        assert_eq!(
+            U::from_base_be_bounded(10, [0], 0),
+            Err(BoundedBaseConvertError::TooManyDigits(0))
+        );
+        assert_eq!(
+            U::from_base_be_bounded(10, [1, 10], 5),
+            Err(BaseConvertError::InvalidDigit(10, 10).into())
+        );
+        assert_eq!(
+            U::from_base_be_bounded(1, [0], 5),
+            Err(BaseConvertError::InvalidBase(1).into())
+        );
+        // Overflow is reported for the digits within the bound.
+        assert_eq!(
+            U::from_base_be_bounded(10, digits.iter().copied(), 100),
+            Err(BaseConvertError::Overflow.into())
+        );
+
+        // Infinite input terminates, also without a size hint.
+        assert_eq!(
+            U::from_base_be_bounded(10, core::iter::repeat(0), 1000),
+            Err(BoundedBaseConvertError::TooManyDigits(1000))
+        );
+        let mut read = 0;
+        let counted = core::iter::from_fn(|| {
+            read += 1;
+            Some(0)
+        });
+        assert_eq!(
+            U::from_base_be_bounded(10, counted, 1000),
+            Err(BoundedBaseConvertError::TooManyDigits(1000))
+        );
+        assert_eq!(read, 1001);
+    }
+
+    #[test]
+    fn test_to_base_be_len() {
+        for (value, base) in [
+            (Uint::<256, 4>::ZERO, 10),
+            (Uint::from(123456789), 10),
+            (N, 10),
+            (N, 2),
+            (N, 10000000000000000000_u64),
+        ] {
+            let mut digits = value.to_base_be(base);
+            let len = digits.len();
+            assert_eq!(len, value.to_base_le(base).count());
+            assert_eq!(digits.next().is_some(), len > 0);
+            assert_eq!(digits.len(), len.saturating_sub(1));
+            assert_eq!(digits.count(), len.saturating_sub(1));
+        }
+    }
+
     #[test]
     fn test_from_base_le() {
         assert_eq!(
@@ -273,6 +534,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_base_le_checked() {
+        use core::iter::{once, repeat};
+        type U = Uint<64, 1>;
+        // Infinite trailing zeros terminate.
+        let digits = [9, 8, 7].into_iter().chain(repeat(0));
+        assert_eq!(U::from_base_le_checked(10, digits), Ok((U::from(789), 20)));
+        let mut digits = N.to_base_le(1 << 32).chain(repeat(0));
+        assert_eq!(
+            Uint::<256, 4>::from_base_le_checked(1 << 32, digits.by_ref()),
+            Ok((N, 8))
+        );
+        assert_eq!(digits.next(), Some(0));
+        // Overflow is reported on the offending digit.
+        let digits = repeat(0).take(19).chain(once(2)).chain(repeat(0));
+        assert_eq!(
+            U::from_base_le_checked(10, digits),
+            Err(BaseConvertError::Overflow)
+        );
+        assert_eq!(
+            U::from_base_le_checked(10, [1, 10]),
+            Err(BaseConvertError::InvalidDigit(10, 10))
+        );
+        assert_eq!(
+            U::from_base_le_checked(1, repeat(0)),
+            Err(BaseConvertError::InvalidBase(1))
+        );
+        assert_eq!(
+            Uint::<0, 0>::from_base_le_checked(10, repeat(1)),
+            Ok((Uint::ZERO, 0))
+        );
+        // Short input consumes everything.
+        assert_eq!(U::from_base_le_checked(10, [1, 2]), Ok((U::from(21), 2)));
+        // The unchecked variant still validates the tail.
+        assert_eq!(
+            U::from_base_le(10, repeat(0).take(25).chain(once(1))),
+            Err(BaseConvertError::Overflow)
+        );
+        assert_eq!(Uint::<0, 0>::from_base_le(10, [0, 0]), Ok(Uint::ZERO));
+        assert_eq!(
+            Uint::<0, 0>::from_base_le(10, [0, 11]),
+            Err(BaseConvertError::InvalidDigit(11, 10))
+        );
+    }
+
     #[test]
     fn test_to_base_be() {
         assert_eq!(
@@ -74,6 +74,26 @@ impl<const BITS: usize, const LIMBS: usize> Bits<BITS, LIMBS> {
     pub fn as_uint_mut(&mut self) -> &mut Uint<BITS, LIMBS> {
         &mut self.0
     }
+
+    /// Iterates over all `BITS` bits, from index `0` (least significant) to
+    /// `BITS - 1`.
+    ///
+    /// Use `.rev()` for most-significant-first order. Note that iterating the
+    /// [`Uint`] itself yields limbs instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Bits, Uint};
+    /// let bits = Bits::from(Uint::<4, 1>::from(0b0011));
+    /// assert!(bits.iter().eq([true, true, false, false]));
+    /// assert!(bits.iter().rev().eq([false, false, true, true]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = bool> + ExactSizeIterator + '_ {
+        (0..BITS).map(move |i| self.0.bit(i))
+    }
 }
 
 macro_rules! forward_attributes {
@@ -261,6 +281,65 @@ impl<const BITS: usize, const LIMBS: usize> Bits<BITS, LIMBS> {
     }
 }
 
+/// Set algebra, treating [`Bits`] as the set of indices of its set bits.
+impl<const BITS: usize, const LIMBS: usize> Bits<BITS, LIMBS> {
+    /// Returns the bits set in `self` or `other`, same as `self | other`.
+    #[must_use]
+    #[inline]
+    pub fn union(self, other: Self) -> Self {
+        self | other
+    }
+
+    /// Returns the bits set in both `self` and `other`, same as
+    /// `self & other`.
+    #[must_use]
+    #[inline]
+    pub fn intersection(self, other: Self) -> Self {
+        self & other
+    }
+
+    /// Returns the bits set in `self` but not in `other`, same as
+    /// `self & !other`.
+    #[must_use]
+    #[inline]
+    pub fn difference(self, other: Self) -> Self {
+        self & !other
+    }
+
+    /// Returns the bits set in exactly one of `self` and `other`, same as
+    /// `self ^ other`.
+    #[must_use]
+    #[inline]
+    pub fn symmetric_difference(self, other: Self) -> Self {
+        self ^ other
+    }
+
+    /// Returns `true` if every bit set in `self` is also set in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Bits, aliases::*};
+    /// let a = Bits::from(U256::from(0b0110));
+    /// let b = Bits::from(U256::from(0b1110));
+    /// assert!(a.is_subset(b));
+    /// assert!(!b.is_subset(a));
+    /// assert!(a.is_disjoint(!b));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_subset(self, other: Self) -> bool {
+        self.difference(other) == Self::ZERO
+    }
+
+    /// Returns `true` if `self` and `other` have no set bits in common.
+    #[must_use]
+    #[inline]
+    pub fn is_disjoint(self, other: Self) -> bool {
+        self.intersection(other) == Self::ZERO
+    }
+}
+
 impl<const BITS: usize, const LIMBS: usize> Index<usize> for Bits<BITS, LIMBS> {
     type Output = bool;
 
@@ -416,3 +495,65 @@ macro_rules! impl_shift {
 
 impl_shift!(Shl, shl, ShlAssign, shl_assign);
 impl_shift!(Shr, shr, ShrAssign, shr_assign);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_iter() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type B = Bits<BITS, LIMBS>;
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                let bits = B::from(value);
+                assert_eq!(bits.iter().len(), BITS);
+                assert_eq!(bits.iter().count(), BITS);
+                assert!(bits.iter().enumerate().all(|(i, bit)| bit == value.bit(i)));
+                assert!(bits.iter().rev().eq((0..BITS).rev().map(|i| value.bit(i))));
+                assert_eq!(bits.iter().filter(|&bit| bit).count(), value.count_ones());
+                // Meet in the middle.
+                let mut iter = bits.iter();
+                let (mut front, mut back) = (0, BITS);
+                while let Some(bit) = iter.next() {
+                    assert_eq!(bit, value.bit(front));
+                    front += 1;
+                    if let Some(bit) = iter.next_back() {
+                        back -= 1;
+                        assert_eq!(bit, value.bit(back));
+                    }
+                }
+                assert_eq!(front, back);
+            });
+        });
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)] // Generated code
+    fn test_set_operations() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type B = Bits<BITS, LIMBS>;
+            proptest!(|(a: Uint<BITS, LIMBS>, b: Uint<BITS, LIMBS>)| {
+                let (a, b) = (B::from(a), B::from(b));
+                let (union, intersection) = (a.union(b), a.intersection(b));
+                let (difference, symmetric) = (a.difference(b), a.symmetric_difference(b));
+                for i in 0..BITS {
+                    assert_eq!(union[i], a[i] || b[i]);
+                    assert_eq!(intersection[i], a[i] && b[i]);
+                    assert_eq!(difference[i], a[i] && !b[i]);
+                    assert_eq!(symmetric[i], a[i] != b[i]);
+                }
+                assert_eq!(a.is_subset(b), (0..BITS).all(|i| !a[i] || b[i]));
+                assert_eq!(a.is_disjoint(b), (0..BITS).all(|i| !(a[i] && b[i])));
+                assert!(intersection.is_subset(a));
+                assert!(a.is_subset(union));
+                assert!(difference.is_disjoint(b));
+                assert!(B::ZERO.is_subset(a));
+                assert!(a.is_subset(a));
+            });
+        });
+    }
+}
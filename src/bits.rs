@@ -1,9 +1,29 @@
-use crate::Uint;
-use core::ops::{
-    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
-    ShrAssign,
+use crate::{algorithms, nlimbs, Uint};
+use core::{
+    fmt,
+    ops::{
+        BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Index, Not, Shl, ShlAssign,
+        Shr, ShrAssign,
+    },
 };
 
+/// Error for [`try_shl_assign`][Uint::try_shl_assign] and
+/// [`try_shr_assign`][Uint::try_shr_assign].
+///
+/// Non-zero bits would have been shifted out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ShiftOverflow;
+
+#[cfg(feature = "std")]
+impl std::error::Error for ShiftOverflow {}
+
+impl fmt::Display for ShiftOverflow {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("non-zero bits would be shifted out")
+    }
+}
+
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Returns whether a specific bit is set.
     ///
@@ -32,6 +52,70 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         }
     }
 
+    /// Sets all bits at the given indices.
+    ///
+    /// Indices that exceed the bit width of the number are ignored, like in
+    /// [`Uint::set_bit`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U64};
+    /// # uint!{
+    /// let mut flags = 0_U64;
+    /// flags.set_bits_from([0, 3, 5, 64]);
+    /// assert_eq!(flags, 0b10_1001_U64);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_bits_from<I: IntoIterator<Item = usize>>(&mut self, indices: I) {
+        for index in indices {
+            if index < BITS {
+                self.limbs[index / 64] |= 1 << (index % 64);
+            }
+        }
+    }
+
+    /// Clears all bits at the given indices.
+    ///
+    /// Indices that exceed the bit width of the number are ignored, like in
+    /// [`Uint::set_bit`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U64};
+    /// # uint!{
+    /// let mut flags = 0xff_U64;
+    /// flags.clear_bits_from([0, 3, 5, 64]);
+    /// assert_eq!(flags, 0b1101_0110_U64);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn clear_bits_from<I: IntoIterator<Item = usize>>(&mut self, indices: I) {
+        for index in indices {
+            if index < BITS {
+                self.limbs[index / 64] &= !(1 << (index % 64));
+            }
+        }
+    }
+
+    /// Returns `true` if the value is even.
+    ///
+    /// The zero-width `Uint<0, 0>` only holds the value zero and is even.
+    #[must_use]
+    #[inline]
+    pub const fn is_even(&self) -> bool {
+        LIMBS == 0 || self.limbs[0] & 1 == 0
+    }
+
+    /// Returns `true` if the value is odd.
+    #[must_use]
+    #[inline]
+    pub const fn is_odd(&self) -> bool {
+        !self.is_even()
+    }
+
     /// Returns a specific byte. The byte at index `0` is the least significant
     /// byte (little endian).
     ///
@@ -68,16 +152,26 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     #[must_use]
     #[track_caller]
     pub const fn byte(&self, index: usize) -> u8 {
-        #[cfg(target_endian = "little")]
-        {
-            self.as_le_slice()[index]
-        }
+        self.le_byte(index)
+    }
 
-        #[cfg(target_endian = "big")]
+    /// Returns the byte at `index` in the little-endian representation.
+    ///
+    /// This is computed from the limbs and behaves the same on little- and
+    /// big-endian targets, unlike [`Self::as_le_slice`] which is only
+    /// available on little-endian targets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than [`Self::BYTES`].
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn le_byte(&self, index: usize) -> u8 {
+        assert!(index < Self::BYTES, "Byte index out of range.");
         #[allow(clippy::cast_possible_truncation)] // intentional
-        {
-            (self.limbs[index / 8] >> ((index % 8) * 8)) as u8
-        }
+        let byte = (self.limbs[index / 8] >> ((index % 8) * 8)) as u8;
+        byte
     }
 
     /// Reverses the order of bits in the integer. The least significant bit
@@ -96,6 +190,305 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         self
     }
 
+    /// Const version of [`Uint::reverse_bits`].
+    ///
+    /// Useful for computing bit-reversal permutations in constants, like FFT
+    /// twiddle tables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// const REVERSED: U256 = 1_U256.reverse_bits_const();
+    /// assert_eq!(REVERSED, 1_U256 << 255);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn reverse_bits_const(self) -> Self {
+        let mut limbs = [0; LIMBS];
+        let shift = (64 - BITS % 64) % 64;
+        let mut i = 0;
+        while i < LIMBS {
+            let mut limb = self.limbs[LIMBS - 1 - i].reverse_bits() >> shift;
+            if shift != 0 && i + 1 < LIMBS {
+                limb |= self.limbs[LIMBS - 2 - i].reverse_bits() << (64 - shift);
+            }
+            limbs[i] = limb;
+            i += 1;
+        }
+        Self::from_limbs(limbs)
+    }
+
+    /// Reverses the order of bits in place, see [`Uint::reverse_bits`].
+    #[inline]
+    pub fn reverse_bits_mut(&mut self) {
+        *self = self.reverse_bits();
+    }
+
+    /// Reverses the order of the low `n` bits and clears the bits above them.
+    ///
+    /// This is the index permutation of a radix-2 FFT or NTT of size $2^n$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n > BITS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(0b0011_U256.reverse_low_bits(3), 0b110_U256);
+    /// assert_eq!(0b1011_U256.reverse_low_bits(3), 0b110_U256);
+    /// assert_eq!(1_U256.reverse_low_bits(10), 512_U256);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn reverse_low_bits(self, n: usize) -> Self {
+        assert!(n <= BITS, "n must be at most BITS");
+        self.reverse_bits() >> (BITS - n)
+    }
+
+    /// Reverses the order of the `u64` limbs, leaving the bits and bytes
+    /// within each limb unchanged.
+    ///
+    /// This reinterprets a value whose limbs were stored most-significant
+    /// first, as some FFI boundaries do. It is a limb-order swap only, unlike
+    /// a byte swap (`swap_bytes` in `num-traits`) or [`Uint::reverse_bits`].
+    ///
+    /// It is a compile time error if `BITS` is not a multiple of 64, as the
+    /// lowest limb would not fit in the partial top limb:
+    ///
+    /// ```compile_fail
+    /// # use ruint::Uint;
+    /// let _ = Uint::<100, 2>::ZERO.reverse_limbs();
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::U192;
+    /// let value = U192::from_limbs([1, 2, 3]);
+    /// assert_eq!(value.reverse_limbs(), U192::from_limbs([3, 2, 1]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn reverse_limbs(self) -> Self {
+        let () = FullLimbs::<BITS>::OK;
+        let mut limbs = [0; LIMBS];
+        let mut i = 0;
+        while i < LIMBS {
+            limbs[i] = self.limbs[LIMBS - 1 - i];
+            i += 1;
+        }
+        Self::from_limbs(limbs)
+    }
+
+    /// Returns a value with the `n` least significant bits set.
+    ///
+    /// Returns [`Self::MAX`] if `n >= BITS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U64};
+    /// # uint!{
+    /// assert_eq!(U64::low_mask(0), 0_U64);
+    /// assert_eq!(U64::low_mask(12), 0xfff_U64);
+    /// assert_eq!(U64::low_mask(100), U64::MAX);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn low_mask(n: usize) -> Self {
+        if n >= BITS {
+            return Self::MAX;
+        }
+        let mut limbs = [0; LIMBS];
+        let mut i = 0;
+        while i < n / 64 {
+            limbs[i] = u64::MAX;
+            i += 1;
+        }
+        if n % 64 != 0 {
+            limbs[n / 64] = (1 << (n % 64)) - 1;
+        }
+        Self::from_limbs(limbs)
+    }
+
+    /// Returns a value with the `n` most significant bits set.
+    ///
+    /// Returns [`Self::MAX`] if `n >= BITS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U64};
+    /// # uint!{
+    /// assert_eq!(U64::high_mask(0), 0_U64);
+    /// assert_eq!(U64::high_mask(12), 0xfff0000000000000_U64);
+    /// assert_eq!(U64::high_mask(100), U64::MAX);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn high_mask(n: usize) -> Self {
+        if n >= BITS {
+            return Self::MAX;
+        }
+        let low = Self::low_mask(BITS - n);
+        let mut limbs = Self::MAX.limbs;
+        let mut i = 0;
+        while i < LIMBS {
+            limbs[i] ^= low.limbs[i];
+            i += 1;
+        }
+        Self::from_limbs(limbs)
+    }
+
+    /// Splits `self` into its high `HB` bits and low `LB` bits.
+    ///
+    /// This is the inverse of [`Self::concat`].
+    ///
+    /// It is a compile time error if `HB + LB != BITS` or the limb counts do
+    /// not match the bit sizes:
+    ///
+    /// ```compile_fail
+    /// # use ruint::{Uint, aliases::*};
+    /// let _: (U128, U128) = Uint::<255, 4>::ZERO.split();
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// let (high, low): (U128, U128) =
+    ///     0x0123456789abcdef_fedcba9876543210_00000000000000ff_0000000000000001_U256.split();
+    /// assert_eq!(high, 0x0123456789abcdef_fedcba9876543210_U128);
+    /// assert_eq!(low, 0x00000000000000ff_0000000000000001_U128);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn split<const HB: usize, const HL: usize, const LB: usize, const LL: usize>(
+        self,
+    ) -> (Uint<HB, HL>, Uint<LB, LL>) {
+        let () = SplitWidths::<BITS, HB, HL, LB, LL>::OK;
+        let low = Uint::wrapping_from_limbs_slice(self.as_limbs());
+        let high = Uint::wrapping_from_limbs_slice((self >> LB).as_limbs());
+        (high, low)
+    }
+
+    /// Joins `high` and `low` into a single integer with `high` in the most
+    /// significant `HB` bits.
+    ///
+    /// This is the inverse of [`Self::split`].
+    ///
+    /// It is a compile time error if `HB + LB != BITS` or the limb counts do
+    /// not match the bit sizes:
+    ///
+    /// ```compile_fail
+    /// # use ruint::{Uint, aliases::*};
+    /// let _ = Uint::<255, 4>::concat(U128::ZERO, U128::ZERO);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(
+    ///     U256::concat(0x01_U128, 0x02_U128),
+    ///     0x00000000000000000000000000000001_00000000000000000000000000000002_U256
+    /// );
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn concat<const HB: usize, const HL: usize, const LB: usize, const LL: usize>(
+        high: Uint<HB, HL>,
+        low: Uint<LB, LL>,
+    ) -> Self {
+        let () = SplitWidths::<BITS, HB, HL, LB, LL>::OK;
+        let high = Self::wrapping_from_limbs_slice(high.as_limbs());
+        let low = Self::wrapping_from_limbs_slice(low.as_limbs());
+        (high << LB) | low
+    }
+
+    /// Splits `self` into `N` chunks of `CB` bits, least significant first.
+    ///
+    /// This is the inverse of [`Self::from_chunks`].
+    ///
+    /// It is a compile time error if `CB * N != BITS` or `CL` does not match
+    /// `CB`:
+    ///
+    /// ```compile_fail
+    /// # use ruint::{Uint, aliases::*};
+    /// let _: [U64; 3] = U256::ZERO.as_chunks();
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// let chunks: [U64; 4] =
+    ///     0x0123456789abcdef_fedcba9876543210_00000000000000ff_0000000000000001_U256.as_chunks();
+    /// assert_eq!(
+    ///     chunks,
+    ///     [0x01_U64, 0xff_U64, 0xfedcba9876543210_U64, 0x0123456789abcdef_U64]
+    /// );
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_chunks<const CB: usize, const CL: usize, const N: usize>(self) -> [Uint<CB, CL>; N] {
+        let () = ChunkWidths::<BITS, CB, CL, N>::OK;
+        core::array::from_fn(|i| Uint::wrapping_from_limbs_slice((self >> (i * CB)).as_limbs()))
+    }
+
+    /// Joins `N` chunks of `CB` bits into a single integer, least significant
+    /// first.
+    ///
+    /// This is the inverse of [`Self::as_chunks`].
+    ///
+    /// It is a compile time error if `CB * N != BITS` or `CL` does not match
+    /// `CB`:
+    ///
+    /// ```compile_fail
+    /// # use ruint::{Uint, aliases::*};
+    /// let _ = U256::from_chunks([U64::ZERO; 3]);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(
+    ///     U256::from_chunks([0x01_U64, 0xff_U64, 0xfedcba9876543210_U64, 0x0123456789abcdef_U64]),
+    ///     0x0123456789abcdef_fedcba9876543210_00000000000000ff_0000000000000001_U256
+    /// );
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_chunks<const CB: usize, const CL: usize, const N: usize>(
+        chunks: [Uint<CB, CL>; N],
+    ) -> Self {
+        let () = ChunkWidths::<BITS, CB, CL, N>::OK;
+        let mut result = Self::ZERO;
+        for (i, chunk) in chunks.iter().enumerate() {
+            result |= Self::wrapping_from_limbs_slice(chunk.as_limbs()) << (i * CB);
+        }
+        result
+    }
+
     /// Returns the number of leading zeros in the binary representation of
     /// `self`.
     #[inline]
@@ -134,6 +527,31 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
             })
     }
 
+    /// Splits `self` into an odd part and a power of two, returning
+    /// `(d, s)` such that `self` $= d ⋅ 2^s$ with $d$ odd.
+    ///
+    /// For zero, which has no such decomposition, `(ZERO, BITS)` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(24_U256.remove_twos(), (3_U256, 3));
+    /// assert_eq!(7_U256.remove_twos(), (7_U256, 0));
+    /// assert_eq!(0_U256.remove_twos(), (0_U256, 256));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn remove_twos(self) -> (Self, usize) {
+        let shift = self.trailing_zeros();
+        if shift >= BITS {
+            return (Self::ZERO, BITS);
+        }
+        (self >> shift, shift)
+    }
+
     /// Returns the number of trailing ones in the binary representation of
     /// `self`.
     #[inline]
@@ -151,6 +569,8 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     #[inline]
     #[must_use]
     pub fn count_ones(&self) -> usize {
+        // OPT: LLVM vectorizes this loop. An explicit `core::simd` popcount
+        // benchmarked about twice as slow for `U4096`.
         self.as_limbs()
             .iter()
             .map(|limb| limb.count_ones() as usize)
@@ -164,6 +584,75 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         BITS - self.count_ones()
     }
 
+    /// Returns the number of bit positions in which `self` and `other`
+    /// differ, i.e. `(self ^ other).count_ones()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U64};
+    /// # uint!{
+    /// assert_eq!(0b1011_U64.hamming_distance(&0b0110_U64), 3);
+    /// assert_eq!(U64::MAX.hamming_distance(&0_U64), 64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn hamming_distance(&self, other: &Self) -> usize {
+        self.as_limbs()
+            .iter()
+            .zip(other.as_limbs())
+            .map(|(a, b)| (a ^ b).count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns an iterator over the indices of the set bits, in increasing
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert!(0b1010_U256.iter_ones().eq([1, 3]));
+    /// assert!((1_U256 << 200_usize).iter_ones().eq([200]));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.limbs.iter().enumerate().flat_map(|(i, &limb)| {
+            let mut limb = limb;
+            core::iter::from_fn(move || {
+                if limb == 0 {
+                    return None;
+                }
+                let index = i * 64 + limb.trailing_zeros() as usize;
+                limb &= limb - 1;
+                Some(index)
+            })
+        })
+    }
+
+    /// Returns a wrapper whose [`Debug`](fmt::Debug) implementation renders
+    /// the indices of the set bits as a list.
+    ///
+    /// This makes sparse flag registers readable in `dbg!` output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// let flags = (1_U256 << 200_usize) | 0b101_U256;
+    /// assert_eq!(format!("{:?}", flags.debug_bits()), "[0, 2, 200]");
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn debug_bits(&self) -> impl fmt::Debug + '_ {
+        DebugBits(self)
+    }
+
     /// Length of the number in bits ignoring leading zeros.
     #[must_use]
     #[inline]
@@ -220,7 +709,19 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// shifted out would be non-zero.
     ///
     /// Note: This differs from [`u64::checked_shl`] which returns `None` if the
-    /// shift is larger than BITS (which is IMHO not very useful).
+    /// shift is larger than BITS (which is IMHO not very useful). Use
+    /// [`Uint::strict_shl`] to panic instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(1_U256.checked_shl(255), Some(1_U256 << 255));
+    /// assert_eq!(2_U256.checked_shl(255), None);
+    /// assert_eq!(0_U256.checked_shl(1000), Some(0_U256));
+    /// # }
+    /// ```
     #[inline(always)]
     #[must_use]
     pub fn checked_shl(self, rhs: usize) -> Option<Self> {
@@ -230,6 +731,28 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         }
     }
 
+    /// Strict left shift by `rhs` bits.
+    ///
+    /// Returns $\mathtt{self} ⋅ 2^{\mathtt{rhs}}$, like
+    /// [`Uint::checked_shl`] but panicking where it would return [`None`].
+    ///
+    /// Note: Unlike `u64::strict_shl` this does not panic on large shifts,
+    /// only when non-zero bits are shifted out. Shifting zero by any amount is
+    /// fine.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result would be $≥ 2^{\mathtt{BITS}}$.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn strict_shl(self, rhs: usize) -> Self {
+        match self.overflowing_shl(rhs) {
+            (value, false) => value,
+            _ => panic!("attempt to shift left with overflow"),
+        }
+    }
+
     /// Saturating left shift by `rhs` bits.
     ///
     /// Returns $\mathtt{self} ⋅ 2^{\mathtt{rhs}}$ or [`Uint::MAX`] if the
@@ -254,22 +777,13 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// the shift is larger than `BITS` (which is IMHO not very useful).
     #[inline]
     #[must_use]
-    pub fn overflowing_shl(self, rhs: usize) -> (Self, bool) {
-        let (limbs, bits) = (rhs / 64, rhs % 64);
-        if limbs >= LIMBS {
-            return (Self::ZERO, self != Self::ZERO);
-        }
-
-        let word_bits = 64;
-        let mut r = Self::ZERO;
-        let mut carry = 0;
-        for i in 0..Self::LIMBS - limbs {
-            let x = self.limbs[i];
-            r.limbs[i + limbs] = (x << bits) | carry;
-            carry = (x >> (word_bits - bits - 1)) >> 1;
+    pub fn overflowing_shl(mut self, rhs: usize) -> (Self, bool) {
+        let mut overflow = algorithms::shift_left(&mut self.limbs, rhs);
+        if LIMBS > 0 {
+            overflow |= self.limbs[LIMBS - 1] > Self::MASK;
+            self.limbs[LIMBS - 1] &= Self::MASK;
         }
-        r.limbs[LIMBS - 1] &= Self::MASK;
-        (r, carry != 0)
+        (self, overflow)
     }
 
     /// Left shift by `rhs` bits.
@@ -284,6 +798,38 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         self.overflowing_shl(rhs).0
     }
 
+    /// Left shift by `rhs` bits in place, failing on overflow.
+    ///
+    /// This is the assigning form of [`Uint::checked_shl`]. On overflow `self`
+    /// is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ShiftOverflow`] if non-zero bits would be shifted out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*, ShiftOverflow};
+    /// # uint!{
+    /// let mut value = 3_U8;
+    /// assert_eq!(value.try_shl_assign(6), Ok(()));
+    /// assert_eq!(value, 192_U8);
+    /// assert_eq!(value.try_shl_assign(1), Err(ShiftOverflow));
+    /// assert_eq!(value, 192_U8);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_shl_assign(&mut self, rhs: usize) -> Result<(), ShiftOverflow> {
+        match self.overflowing_shl(rhs) {
+            (value, false) => {
+                *self = value;
+                Ok(())
+            }
+            _ => Err(ShiftOverflow),
+        }
+    }
+
     /// Checked right shift by `rhs` bits.
     ///
     /// $$
@@ -291,10 +837,21 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// $$
     ///
     /// Returns the above or [`None`] if the division is not exact. This is the
-    /// same as
+    /// same as non-zero bits being shifted out.
     ///
     /// Note: This differs from [`u64::checked_shr`] which returns `None` if the
-    /// shift is larger than BITS (which is IMHO not very useful).
+    /// shift is larger than BITS (which is IMHO not very useful). Use
+    /// [`Uint::strict_shr`] to panic instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(6_U8.checked_shr(1), Some(3_U8));
+    /// assert_eq!(6_U8.checked_shr(2), None);
+    /// # }
+    /// ```
     #[inline(always)]
     #[must_use]
     pub fn checked_shr(self, rhs: usize) -> Option<Self> {
@@ -304,6 +861,28 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         }
     }
 
+    /// Strict right shift by `rhs` bits.
+    ///
+    /// Returns $\frac{\mathtt{self}}{2^{\mathtt{rhs}}}$, like
+    /// [`Uint::checked_shr`] but panicking where it would return [`None`].
+    ///
+    /// Note: Unlike `u64::strict_shr` this does not panic on large shifts,
+    /// only when non-zero bits are shifted out. Shifting zero by any amount is
+    /// fine.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the division is not exact.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn strict_shr(self, rhs: usize) -> Self {
+        match self.overflowing_shr(rhs) {
+            (value, false) => value,
+            _ => panic!("attempt to shift right with overflow"),
+        }
+    }
+
     /// Right shift by `rhs` bits with underflow detection.
     ///
     /// $$
@@ -318,21 +897,9 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// the shift is larger than `BITS` (which is IMHO not very useful).
     #[inline]
     #[must_use]
-    pub fn overflowing_shr(self, rhs: usize) -> (Self, bool) {
-        let (limbs, bits) = (rhs / 64, rhs % 64);
-        if limbs >= LIMBS {
-            return (Self::ZERO, self != Self::ZERO);
-        }
-
-        let word_bits = 64;
-        let mut r = Self::ZERO;
-        let mut carry = 0;
-        for i in 0..LIMBS - limbs {
-            let x = self.limbs[LIMBS - 1 - i];
-            r.limbs[LIMBS - 1 - i - limbs] = (x >> bits) | carry;
-            carry = (x << (word_bits - bits - 1)) << 1;
-        }
-        (r, carry != 0)
+    pub fn overflowing_shr(mut self, rhs: usize) -> (Self, bool) {
+        let overflow = algorithms::shift_right(&mut self.limbs, rhs);
+        (self, overflow)
     }
 
     /// Right shift by `rhs` bits.
@@ -350,6 +917,39 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         self.overflowing_shr(rhs).0
     }
 
+    /// Right shift by `rhs` bits in place, failing if the division is not
+    /// exact.
+    ///
+    /// This is the assigning form of [`Uint::checked_shr`]. On failure `self`
+    /// is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ShiftOverflow`] if non-zero bits would be shifted out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*, ShiftOverflow};
+    /// # uint!{
+    /// let mut value = 12_U8;
+    /// assert_eq!(value.try_shr_assign(2), Ok(()));
+    /// assert_eq!(value, 3_U8);
+    /// assert_eq!(value.try_shr_assign(1), Err(ShiftOverflow));
+    /// assert_eq!(value, 3_U8);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_shr_assign(&mut self, rhs: usize) -> Result<(), ShiftOverflow> {
+        match self.overflowing_shr(rhs) {
+            (value, false) => {
+                *self = value;
+                Ok(())
+            }
+            _ => Err(ShiftOverflow),
+        }
+    }
+
     /// Arithmetic shift right by `rhs` bits.
     #[inline]
     #[must_use]
@@ -389,6 +989,43 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         let rhs = rhs % BITS;
         self.rotate_left(BITS - rhs)
     }
+
+    /// Rotates the bits by a signed `amount`, to the left for positive and to
+    /// the right for negative amounts. The amount is reduced modulo `BITS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U8};
+    /// # uint!{
+    /// assert_eq!(0b0000_0110_U8.rotate(2), 0b0001_1000_U8);
+    /// assert_eq!(0b0000_0110_U8.rotate(-2), 0b1000_0001_U8);
+    /// assert_eq!(0b0000_0110_U8.rotate(-10), 0b1000_0001_U8);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn rotate(self, amount: isize) -> Self {
+        if amount >= 0 {
+            self.rotate_left(amount.unsigned_abs())
+        } else {
+            self.rotate_right(amount.unsigned_abs())
+        }
+    }
+}
+
+/// Bit access like [`Uint::bit`], indices beyond `BITS` read as `false`.
+impl<const BITS: usize, const LIMBS: usize> Index<usize> for Uint<BITS, LIMBS> {
+    type Output = bool;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        if self.bit(index) {
+            &true
+        } else {
+            &false
+        }
+    }
 }
 
 impl<const BITS: usize, const LIMBS: usize> Not for Uint<BITS, LIMBS> {
@@ -649,13 +1286,320 @@ impl_shift!(usize, u8, u16, u32, isize, i8, i16, i32);
 #[cfg(target_pointer_width = "64")]
 impl_shift!(u64, i64);
 
+/// Return type of [`Uint::debug_bits`].
+struct DebugBits<'a, const BITS: usize, const LIMBS: usize>(&'a Uint<BITS, LIMBS>);
+
+impl<const BITS: usize, const LIMBS: usize> fmt::Debug for DebugBits<'_, BITS, LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.iter_ones()).finish()
+    }
+}
+
+/// Compile time check of the widths in [`Uint::split`] and [`Uint::concat`].
+struct SplitWidths<
+    const BITS: usize,
+    const HB: usize,
+    const HL: usize,
+    const LB: usize,
+    const LL: usize,
+>;
+
+impl<const BITS: usize, const HB: usize, const HL: usize, const LB: usize, const LL: usize>
+    SplitWidths<BITS, HB, HL, LB, LL>
+{
+    const OK: () = {
+        assert!(HB + LB == BITS, "split widths must add up to BITS");
+        assert!(HL == nlimbs(HB), "HL must be nlimbs(HB)");
+        assert!(LL == nlimbs(LB), "LL must be nlimbs(LB)");
+    };
+}
+
+/// Compile time check that [`Uint::reverse_limbs`] only uses full limbs.
+struct FullLimbs<const BITS: usize>;
+
+impl<const BITS: usize> FullLimbs<BITS> {
+    const OK: () = assert!(BITS % 64 == 0, "BITS must be a multiple of 64");
+}
+
+/// Compile time check of the widths in [`Uint::as_chunks`] and
+/// [`Uint::from_chunks`].
+struct ChunkWidths<const BITS: usize, const CB: usize, const CL: usize, const N: usize>;
+
+impl<const BITS: usize, const CB: usize, const CL: usize, const N: usize>
+    ChunkWidths<BITS, CB, CL, N>
+{
+    const OK: () = {
+        assert!(CB * N == BITS, "chunk widths must add up to BITS");
+        assert!(CL == nlimbs(CB), "CL must be nlimbs(CB)");
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{aliases::U128, const_for, nlimbs};
+    use crate::{
+        aliases::{U128, U256},
+        const_for, nlimbs, Bits,
+    };
     use core::cmp::min;
     use proptest::proptest;
 
+    #[test]
+    fn test_split_concat() {
+        proptest!(|(value: U256)| {
+            let (high, low): (U128, U128) = value.split();
+            assert_eq!(U256::from(high), value >> 128);
+            assert_eq!(U256::from(low), value & U256::from(u128::MAX));
+            assert_eq!(U256::concat(high, low), value);
+        });
+        proptest!(|(value: Uint<130, 3>)| {
+            let (high, low): (Uint<65, 2>, Uint<65, 2>) = value.split();
+            assert_eq!(Uint::<130, 3>::concat(high, low), value);
+            let (high, low): (Uint<0, 0>, Uint<130, 3>) = value.split();
+            assert_eq!((high, low), (Uint::ZERO, value));
+            assert_eq!(Uint::<130, 3>::concat(high, low), value);
+            let (high, low): (Uint<130, 3>, Uint<0, 0>) = value.split();
+            assert_eq!((high, low), (value, Uint::ZERO));
+            assert_eq!(Uint::<130, 3>::concat(high, low), value);
+        });
+    }
+
+    #[test]
+    fn test_chunks() {
+        proptest!(|(value: U256)| {
+            let chunks: [Uint<64, 1>; 4] = value.as_chunks();
+            assert_eq!(chunks.map(|c| c.to::<u64>()), value.into_limbs());
+            let chunks: [U128; 2] = value.as_chunks();
+            assert_eq!(U256::concat(chunks[1], chunks[0]), value);
+            let chunks: [Uint<8, 1>; 32] = value.as_chunks();
+            assert_eq!(chunks.map(|c| c.to::<u8>()), value.to_le_bytes::<32>());
+            let chunks: [U256; 1] = value.as_chunks();
+            assert_eq!(chunks, [value]);
+        });
+        proptest!(|(value: U256)| {
+            let chunks: [Uint<64, 1>; 4] = value.as_chunks();
+            assert_eq!(U256::from_chunks(chunks), value);
+            let chunks: [Uint<8, 1>; 32] = value.as_chunks();
+            assert_eq!(U256::from_chunks(chunks), value);
+            let chunks: [Uint<1, 1>; 256] = value.as_chunks();
+            assert_eq!(U256::from_chunks(chunks), value);
+        });
+        proptest!(|(chunks: [Uint<13, 1>; 10])| {
+            assert_eq!(Uint::<130, 3>::from_chunks(chunks).as_chunks(), chunks);
+        });
+        proptest!(|(value: Uint<130, 3>)| {
+            let chunks: [Uint<65, 2>; 2] = value.as_chunks();
+            assert_eq!(Uint::<130, 3>::concat(chunks[1], chunks[0]), value);
+            let chunks: [Uint<13, 1>; 10] = value.as_chunks();
+            let joined = chunks
+                .iter()
+                .rev()
+                .fold(Uint::<130, 3>::ZERO, |acc, c| (acc << 13) | Uint::from(*c));
+            assert_eq!(joined, value);
+        });
+        let chunks: [Uint<0, 0>; 0] = Uint::<0, 0>::ZERO.as_chunks();
+        assert_eq!(chunks, []);
+        assert_eq!(Uint::<0, 0>::from_chunks(chunks), Uint::ZERO);
+    }
+
+    #[test]
+    fn test_index() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            proptest!(|(value: U, index in 0..BITS + 70)| {
+                assert_eq!(value[index], value.bit(index));
+                assert_eq!(value[index], Bits::from(value)[index]);
+            });
+            assert!(!U::MAX[BITS]);
+            assert!(!U::MAX[usize::MAX]);
+        });
+        assert!(U128::from(2)[1]);
+        assert!(!U128::from(2)[0]);
+        assert!(!U128::MAX[128]);
+    }
+
+    #[test]
+    fn test_strict_shift() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            proptest!(|(value: U, shift in 0..BITS + 130)| {
+                if let Some(shifted) = value.checked_shl(shift) {
+                    assert_eq!(value.strict_shl(shift), shifted);
+                }
+                if let Some(shifted) = value.checked_shr(shift) {
+                    assert_eq!(value.strict_shr(shift), shifted);
+                }
+            });
+            assert_eq!(U::ZERO.strict_shl(usize::MAX), U::ZERO);
+            assert_eq!(U::ZERO.strict_shr(usize::MAX), U::ZERO);
+        });
+        assert_eq!(U128::MAX.checked_shl(1), None);
+        assert_eq!(U128::MAX.checked_shr(1), None);
+        assert_eq!(U128::from(2).strict_shl(126), U128::from(1) << 127);
+        assert_eq!(U128::from(2).strict_shr(1), U128::from(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to shift left with overflow")]
+    fn test_strict_shl_overflow() {
+        let _ = U128::MAX.strict_shl(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to shift right with overflow")]
+    fn test_strict_shr_overflow() {
+        let _ = U128::from(3).strict_shr(1);
+    }
+
+    #[test]
+    fn test_le_byte() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                // Endianness independent reference.
+                let bytes = value
+                    .as_limbs()
+                    .iter()
+                    .flat_map(|limb| limb.to_le_bytes());
+                for (i, byte) in bytes.take(U::BYTES).enumerate() {
+                    assert_eq!(value.le_byte(i), byte);
+                    assert_eq!(value.byte(i), byte);
+                }
+                let bytes = value.to_le_bytes_vec();
+                for (i, &byte) in bytes.iter().enumerate() {
+                    assert_eq!(value.le_byte(i), byte);
+                }
+            });
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Byte index out of range.")]
+    fn test_le_byte_out_of_range() {
+        let _ = Uint::<12, 1>::MAX.le_byte(2);
+    }
+
+    #[test]
+    fn test_parity() {
+        const _: () = {
+            assert!(Uint::<0, 0>::ZERO.is_even());
+            assert!(Uint::<1, 1>::from_limbs([1]).is_odd());
+            assert!(U128::from_limbs([2, 1]).is_even());
+        };
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                assert_eq!(value.is_odd(), value.bit(0));
+                assert_ne!(value.is_even(), value.is_odd());
+            });
+        });
+    }
+
+    #[test]
+    fn test_remove_twos() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            assert_eq!(U::ZERO.remove_twos(), (U::ZERO, BITS));
+            proptest!(|(value: U)| {
+                let (odd, shift) = value.remove_twos();
+                if value != U::ZERO {
+                    assert!(odd.is_odd());
+                    assert_eq!(odd << shift, value);
+                    assert_eq!(shift, value.trailing_zeros());
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_reverse_bits() {
+        const _: () = {
+            assert!(Uint::<0, 0>::ZERO
+                .reverse_bits_const()
+                .as_limbs()
+                .is_empty());
+            let reversed = Uint::<65, 2>::from_limbs([3, 0]).reverse_bits_const();
+            assert!(reversed.as_limbs()[0] == 1 << 63 && reversed.as_limbs()[1] == 1);
+            let reversed = U128::from_limbs([1, 0]).reverse_bits_const();
+            assert!(reversed.as_limbs()[0] == 0 && reversed.as_limbs()[1] == 1 << 63);
+        };
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let reversed = value.reverse_bits();
+                assert_eq!(value.reverse_bits_const(), reversed);
+                let mut mutated = value;
+                mutated.reverse_bits_mut();
+                assert_eq!(mutated, reversed);
+                assert_eq!(reversed.reverse_bits_const(), value);
+                for (i, j) in (0..BITS).zip((0..BITS).rev()) {
+                    assert_eq!(reversed.bit(i), value.bit(j));
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_reverse_low_bits() {
+        let permutation = (0..8_u64)
+            .map(|i| U256::from(i).reverse_low_bits(3).to::<u64>())
+            .collect::<Vec<_>>();
+        assert_eq!(permutation, [0, 4, 2, 6, 1, 5, 3, 7]);
+        for i in 0..1024_u64 {
+            let reversed = U256::from(i).reverse_low_bits(10);
+            assert_eq!(reversed.to::<u64>(), i.reverse_bits() >> 54);
+            assert_eq!(reversed.reverse_low_bits(10), U256::from(i));
+        }
+        assert_eq!(U256::MAX.reverse_low_bits(0), U256::ZERO);
+        assert_eq!(U256::MAX.reverse_low_bits(256), U256::MAX);
+        assert_eq!(Uint::<0, 0>::ZERO.reverse_low_bits(0), Uint::ZERO);
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            proptest!(|(value: U, n in 0..=BITS)| {
+                let reversed = value.reverse_low_bits(n);
+                for i in 0..n {
+                    assert_eq!(reversed.bit(i), value.bit(n - 1 - i));
+                }
+                assert_eq!(reversed.reduce_pow2(n), reversed);
+            });
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "n must be at most BITS")]
+    fn test_reverse_low_bits_too_wide() {
+        let _ = U128::ZERO.reverse_low_bits(129);
+    }
+
+    #[test]
+    fn test_iter_ones() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                assert!(value.iter_ones().eq((0..BITS).filter(|&i| value.bit(i))));
+                assert_eq!(value.iter_ones().count(), value.count_ones());
+            });
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_debug_bits() {
+        assert_eq!(format!("{:?}", U256::ZERO.debug_bits()), "[]");
+        assert_eq!(format!("{:?}", U128::from(6).debug_bits()), "[1, 2]");
+        let flags = U256::from(1) | (U256::from(1) << 64_usize) | (U256::from(1) << 255_usize);
+        assert_eq!(format!("{:?}", flags.debug_bits()), "[0, 64, 255]");
+        assert_eq!(format!("{:#?}", U128::from(1).debug_bits()), "[\n    0,\n]");
+    }
+
     #[test]
     fn test_leading_zeros() {
         assert_eq!(Uint::<0, 0>::ZERO.leading_zeros(), 0);
@@ -693,6 +1637,57 @@ mod tests {
         assert_eq!(Uint::<1, 1>::from(1).leading_ones(), 1);
     }
 
+    #[test]
+    fn test_ones_padding() {
+        // Widths where the top limb has padding bits, and their neighbours.
+        const_for!(BITS in [1, 63, 64, 65, 100, 127, 128, 129, 255, 256] {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            assert_eq!(U::MAX.leading_ones(), BITS);
+            assert_eq!(U::MAX.trailing_ones(), BITS);
+            assert_eq!((U::MAX >> 1_usize).leading_ones(), 0);
+            assert_eq!((U::MAX >> 1_usize).trailing_ones(), BITS - 1);
+            assert_eq!((U::MAX << 1_usize).leading_ones(), BITS - 1);
+            assert_eq!((U::MAX << 1_usize).trailing_ones(), 0);
+            proptest!(|(value: U)| {
+                let leading = (0..BITS).rev().take_while(|&i| value.bit(i)).count();
+                let trailing = (0..BITS).take_while(|&i| value.bit(i)).count();
+                assert_eq!(value.leading_ones(), leading);
+                assert_eq!(value.trailing_ones(), trailing);
+            });
+            proptest!(|(n in 0..=BITS)| {
+                assert_eq!(U::high_mask(n).leading_ones(), n);
+                assert_eq!(U::low_mask(n).trailing_ones(), n);
+                assert_eq!((!U::low_mask(n)).leading_ones(), BITS - n);
+            });
+        });
+    }
+
+    #[test]
+    fn test_masks() {
+        const LOW: U128 = U128::low_mask(65);
+        const HIGH: U128 = U128::high_mask(65);
+        assert_eq!(LOW.as_limbs(), &[u64::MAX, 1]);
+        assert_eq!(HIGH.as_limbs(), &[1 << 63, u64::MAX]);
+        assert_eq!(Uint::<0, 0>::low_mask(1), Uint::ZERO);
+        assert_eq!(Uint::<0, 0>::high_mask(1), Uint::ZERO);
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n in 0..BITS + 70)| {
+                let low = U::low_mask(n);
+                let high = U::high_mask(n);
+                assert_eq!(low.count_ones(), min(n, BITS));
+                assert_eq!(high.count_ones(), min(n, BITS));
+                assert_eq!(low.trailing_ones(), min(n, BITS));
+                assert_eq!(high.leading_ones(), min(n, BITS));
+                if let Some(rest) = BITS.checked_sub(n) {
+                    assert_eq!(high, !U::low_mask(rest));
+                }
+            });
+        });
+    }
+
     #[test]
     fn test_most_significant_bits() {
         const_for!(BITS in NON_ZERO {
@@ -713,6 +1708,84 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_overflowing_shift_dropped_limbs() {
+        // Bits in limbs that are shifted out entirely.
+        assert_eq!(
+            Uint::<256, 4>::from_limbs([0, 0, 0, 1]).overflowing_shl(64),
+            (Uint::ZERO, true)
+        );
+        assert_eq!(
+            Uint::<256, 4>::from_limbs([1, 0, 0, 0]).overflowing_shr(64),
+            (Uint::ZERO, true)
+        );
+        // Bits shifted above `BITS` within the top limb.
+        assert_eq!(
+            Uint::<65, 2>::from_limbs([0, 1]).overflowing_shl(1),
+            (Uint::ZERO, true)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::absurd_extreme_comparisons)] // From macro.
+    fn test_overflowing_shift_reference() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, rhs in 0..BITS + 130)| {
+                let mut left = U::ZERO;
+                let mut right = U::ZERO;
+                let mut left_overflow = false;
+                let mut right_overflow = false;
+                for i in (0..BITS).filter(|&i| value.bit(i)) {
+                    if i + rhs < BITS {
+                        left.set_bit(i + rhs, true);
+                    } else {
+                        left_overflow = true;
+                    }
+                    if i >= rhs {
+                        right.set_bit(i - rhs, true);
+                    } else {
+                        right_overflow = true;
+                    }
+                }
+                assert_eq!(value.overflowing_shl(rhs), (left, left_overflow));
+                assert_eq!(value.overflowing_shr(rhs), (right, right_overflow));
+            });
+        });
+    }
+
+    #[test]
+    fn test_try_shift_assign() {
+        let mut value = Uint::<65, 2>::from_limbs([1, 0]);
+        assert_eq!(value.try_shl_assign(64), Ok(()));
+        assert_eq!(value, Uint::from_limbs([0, 1]));
+        assert_eq!(value.try_shl_assign(1), Err(ShiftOverflow));
+        assert_eq!(value, Uint::from_limbs([0, 1]));
+        assert_eq!(value.try_shr_assign(64), Ok(()));
+        assert_eq!(value, Uint::from_limbs([1, 0]));
+        assert_eq!(value.try_shr_assign(1), Err(ShiftOverflow));
+        assert_eq!(value, Uint::from_limbs([1, 0]));
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, rhs in 0..BITS + 130)| {
+                let mut left = value;
+                let result = left.try_shl_assign(rhs);
+                assert_eq!(result.ok().map(|()| left), value.checked_shl(rhs));
+                if result.is_err() {
+                    assert_eq!(left, value);
+                }
+                let mut right = value;
+                let result = right.try_shr_assign(rhs);
+                assert_eq!(result.ok().map(|()| right), value.checked_shr(rhs));
+                if result.is_err() {
+                    assert_eq!(right, value);
+                }
+            });
+        });
+    }
+
     #[test]
     fn test_checked_shl() {
         assert_eq!(
@@ -777,6 +1850,97 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_count_ones() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let expected = (0..BITS).filter(|&i| value.bit(i)).count();
+                assert_eq!(value.count_ones(), expected);
+                assert_eq!(value.count_zeros(), BITS - expected);
+            });
+        });
+        assert_eq!(Uint::<4096, 64>::MAX.count_ones(), 4096);
+    }
+
+    #[test]
+    fn test_hamming_distance() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            proptest!(|(a: U, b: U)| {
+                let distance = a.hamming_distance(&b);
+                assert_eq!(distance, (a ^ b).count_ones());
+                assert_eq!(distance, b.hamming_distance(&a));
+                assert_eq!(a.hamming_distance(&a), 0);
+                assert_eq!(a.hamming_distance(&!a), BITS);
+            });
+        });
+    }
+
+    #[test]
+    fn test_set_bits_from() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            proptest!(|(value: U, indices: Vec<u16>)| {
+                let indices = indices.into_iter().map(usize::from);
+                let mut set = value;
+                let mut clear = value;
+                let mut expected_set = value;
+                let mut expected_clear = value;
+                for index in indices.clone() {
+                    expected_set.set_bit(index, true);
+                    expected_clear.set_bit(index, false);
+                }
+                set.set_bits_from(indices.clone());
+                clear.clear_bits_from(indices);
+                assert_eq!(set, expected_set);
+                assert_eq!(clear, expected_clear);
+            });
+        });
+
+        let mut mask = Uint::<65, 2>::ZERO;
+        mask.set_bits_from([0, 64, 65, 1000, usize::MAX]);
+        assert_eq!(mask, Uint::from(1) | (Uint::from(1) << 64));
+        mask.clear_bits_from(vec![64, 65]);
+        assert_eq!(mask, Uint::from(1));
+        mask.set_bits_from(core::iter::empty());
+        assert_eq!(mask, Uint::from(1));
+        let mut zero = Uint::<0, 0>::ZERO;
+        zero.set_bits_from([0, 1]);
+        assert_eq!(zero, Uint::ZERO);
+    }
+
+    #[test]
+    fn test_reverse_limbs() {
+        let value = Uint::<256, 4>::from_limbs([
+            0x0011_2233_4455_6677,
+            0x8899_aabb_ccdd_eeff,
+            0x0123_4567_89ab_cdef,
+            0xfedc_ba98_7654_3210,
+        ]);
+        assert_eq!(
+            value.reverse_limbs().into_limbs(),
+            [
+                0xfedc_ba98_7654_3210,
+                0x0123_4567_89ab_cdef,
+                0x8899_aabb_ccdd_eeff,
+                0x0011_2233_4455_6677,
+            ]
+        );
+        assert_ne!(value.reverse_limbs(), value.reverse_bits());
+        assert_eq!(Uint::<0, 0>::ZERO.reverse_limbs(), Uint::ZERO);
+        const_for!(BITS in [64, 128, 192, 256, 512] {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                assert_eq!(value.reverse_limbs().reverse_limbs(), value);
+            });
+        });
+    }
+
     #[test]
     fn test_rotate() {
         const_for!(BITS in SIZES {
@@ -789,6 +1953,28 @@ mod tests {
         });
     }
 
+    #[test]
+    #[allow(clippy::cast_possible_wrap, clippy::modulo_one)] // From macro.
+    fn test_rotate_signed() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint::<BITS, LIMBS>;
+            proptest!(|(value: U, n: isize)| {
+                let left = n.rem_euclid(BITS as isize).unsigned_abs();
+                assert_eq!(value.rotate(n), value.rotate_left(left));
+                if n >= 0 {
+                    assert_eq!(value.rotate(n), value.rotate_left(n as usize % BITS));
+                } else {
+                    assert_eq!(value.rotate(n), value.rotate_right(n.unsigned_abs()));
+                }
+                if let Some(neg) = n.checked_neg() {
+                    assert_eq!(value.rotate(n).rotate(neg), value);
+                }
+            });
+        });
+        assert_eq!(Uint::<0, 0>::ZERO.rotate(-3), Uint::ZERO);
+    }
+
     #[test]
     fn test_arithmetic_shr() {
         const_for!(BITS in SIZES {
@@ -193,6 +193,51 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         bytes
     }
 
+    /// Converts the [`Uint`], interpreted as a `BITS`-bit two's complement
+    /// number, to the shortest big-endian two's complement byte vector.
+    ///
+    /// The most significant bit of the first byte is the sign. This is the
+    /// inverse of [`Self::from_be_bytes_signed`]. Zero is encoded as `[0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(U256::MAX.to_be_bytes_signed(), [0xff]);
+    /// assert_eq!(0x80_U256.to_be_bytes_signed(), [0x00, 0x80]);
+    /// assert_eq!((-0x80_U256).to_be_bytes_signed(), [0x80]);
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn to_be_bytes_signed(&self) -> Vec<u8> {
+        let negative = BITS > 0 && self.bit(BITS - 1);
+        let magnitude_bits = if negative {
+            BITS - self.leading_ones()
+        } else {
+            BITS - self.leading_zeros()
+        };
+        // One extra bit for the sign.
+        let len = (magnitude_bits + 1 + 7) / 8;
+        let le = self.as_le_bytes();
+        (0..len)
+            .rev()
+            .map(|i| {
+                let byte = le.get(i).copied().unwrap_or(0);
+                // Sign extend the bits at and above `BITS`.
+                if !negative || 8 * i + 8 <= BITS {
+                    byte
+                } else if 8 * i >= BITS {
+                    0xff
+                } else {
+                    byte | (0xff << (BITS - 8 * i))
+                }
+            })
+            .collect()
+    }
+
     /// Converts a big-endian byte array of size exactly
     /// [`Self::BYTES`] to [`Uint`].
     ///
@@ -214,14 +259,45 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         Self::from_be_slice(&bytes)
     }
 
+    /// Converts a big-endian byte array of size exactly [`Self::BYTES`] to
+    /// [`Uint`], returning [`None`] if the value is too large for the bit-size
+    /// of the Uint.
+    ///
+    /// This is the non-panicking version of [`Self::from_be_bytes`] for
+    /// validating untrusted input. The array length is still checked at
+    /// compile time:
+    ///
+    /// ```compile_fail
+    /// # use ruint::aliases::*;
+    /// let _ = U256::checked_from_be_bytes([0_u8; 31]);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, aliases::*};
+    /// assert_eq!(
+    ///     Uint::<12, 1>::checked_from_be_bytes([0x0f, 0xff]),
+    ///     Some(Uint::from(0xfff))
+    /// );
+    /// assert_eq!(Uint::<12, 1>::checked_from_be_bytes([0x10, 0x00]), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn checked_from_be_bytes<const BYTES: usize>(bytes: [u8; BYTES]) -> Option<Self> {
+        let () = ByteWidth::<BITS, BYTES>::OK;
+        Self::try_from_be_slice(&bytes)
+    }
+
     /// Creates a new integer from a big endian slice of bytes.
     ///
     /// The slice is interpreted as a big endian number. Leading zeros
-    /// are ignored. The slice can be any length.
+    /// are ignored, but the slice can be at most [`Self::BYTES`] long.
     ///
     /// # Panics
     ///
-    /// Panics if the value is larger than fits the [`Uint`].
+    /// Panics if the value is larger than fits the [`Uint`] or the slice is
+    /// longer than [`Self::BYTES`].
     #[must_use]
     #[track_caller]
     #[inline]
@@ -235,9 +311,23 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Creates a new integer from a big endian slice of bytes.
     ///
     /// The slice is interpreted as a big endian number. Leading zeros
-    /// are ignored. The slice can be any length.
+    /// are ignored, but the slice can be at most [`Self::BYTES`] long.
     ///
-    /// Returns [`None`] if the value is larger than fits the [`Uint`].
+    /// Returns [`None`] if the slice is longer than [`Self::BYTES`], even if
+    /// the extra bytes are zero, or if the value is larger than fits the
+    /// [`Uint`]. For
+    /// widths that are not a multiple of eight this includes set bits above
+    /// `BITS` in the most significant byte, so a `Uint<12, 1>` accepts
+    /// `[0x0f, 0xff]` but not `[0x10, 0x00]`.
+    ///
+    /// This can be evaluated at compile time:
+    ///
+    /// ```
+    /// # use ruint::aliases::*;
+    /// const BYTES: &[u8] = &[0x12, 0x34];
+    /// const VALUE: Option<U256> = U256::try_from_be_slice(BYTES);
+    /// assert_eq!(VALUE, Some(U256::from(0x1234)));
+    /// ```
     #[must_use]
     #[inline]
     pub const fn try_from_be_slice(bytes: &[u8]) -> Option<Self> {
@@ -254,6 +344,10 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
                 limbs[i] = u64::from_be_bytes(unsafe { *end.sub((i + 1) * 8).cast() });
                 i += 1;
             }
+            // Full bytes do not imply full limbs, e.g. `Uint<63, 1>`.
+            if Self::LIMBS > 0 && limbs[Self::LIMBS - 1] > Self::MASK {
+                return None;
+            }
             return Some(Self::from_limbs(limbs));
         }
 
@@ -271,6 +365,69 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         Some(Self::from_limbs(limbs))
     }
 
+    /// Creates a new integer from a big endian slice of bytes and returns it
+    /// together with its number of significant bytes.
+    ///
+    /// This is [`Self::try_from_be_slice`] followed by [`Self::byte_len`]. The
+    /// number of leading zero bytes in the input is `bytes.len() - len`.
+    ///
+    /// Returns [`None`] if the value is larger than fits the [`Uint`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U64};
+    /// # uint!{
+    /// assert_eq!(
+    ///     U64::from_be_slice_with_len(&[0, 0, 1, 2]),
+    ///     Some((0x0102_U64, 2))
+    /// );
+    /// assert_eq!(U64::from_be_slice_with_len(&[0, 0]), Some((0_U64, 0)));
+    /// # }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_be_slice_with_len(bytes: &[u8]) -> Option<(Self, usize)> {
+        let value = Self::try_from_be_slice(bytes)?;
+        Some((value, value.byte_len()))
+    }
+
+    /// Creates a new integer from big-endian two's complement bytes.
+    ///
+    /// The most significant bit of the first byte is the sign. The value is
+    /// sign extended to `BITS` bits, or wrapped if the input is wider. Returns
+    /// the wrapped value and whether the input was negative. An empty slice is
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(U256::from_be_bytes_signed(&[0xff]), (U256::MAX, true));
+    /// assert_eq!(
+    ///     U256::from_be_bytes_signed(&[0x00, 0x80]),
+    ///     (0x80_U256, false)
+    /// );
+    /// assert_eq!(U256::from_be_bytes_signed(&[0x80]), (-0x80_U256, true));
+    /// # }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_be_bytes_signed(bytes: &[u8]) -> (Self, bool) {
+        let negative = bytes.first().map_or(false, |&byte| byte & 0x80 != 0);
+        let mut limbs = [if negative { u64::MAX } else { 0 }; LIMBS];
+        for (i, &byte) in bytes.iter().rev().take(LIMBS * 8).enumerate() {
+            let shift = (i % 8) * 8;
+            limbs[i / 8] &= !(0xff << shift);
+            limbs[i / 8] |= u64::from(byte) << shift;
+        }
+        if LIMBS > 0 {
+            limbs[LIMBS - 1] &= Self::MASK;
+        }
+        (Self::from_limbs(limbs), negative)
+    }
+
     /// Converts a little-endian byte array of size exactly
     /// [`Self::BYTES`] to [`Uint`].
     ///
@@ -295,11 +452,12 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Creates a new integer from a little endian slice of bytes.
     ///
     /// The slice is interpreted as a little endian number. Leading zeros
-    /// are ignored. The slice can be any length.
+    /// are ignored, but the slice can be at most [`Self::BYTES`] long.
     ///
     /// # Panics
     ///
-    /// Panics if the value is larger than fits the [`Uint`].
+    /// Panics if the value is larger than fits the [`Uint`] or the slice is
+    /// longer than [`Self::BYTES`].
     #[must_use]
     #[track_caller]
     #[inline]
@@ -313,13 +471,18 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Creates a new integer from a little endian slice of bytes.
     ///
     /// The slice is interpreted as a little endian number. Leading zeros
-    /// are ignored. The slice can be any length.
+    /// are ignored, but the slice can be at most [`Self::BYTES`] long.
     ///
-    /// Returns [`None`] if the value is larger than fits the [`Uint`].
+    /// Returns [`None`] if the slice is longer than [`Self::BYTES`], even if
+    /// the extra bytes are zero, or if the value is larger than fits the
+    /// [`Uint`]. For
+    /// widths that are not a multiple of eight this includes set bits above
+    /// `BITS` in the most significant byte, so a `Uint<12, 1>` accepts
+    /// `[0xff, 0x0f]` but not `[0x00, 0x10]`.
     #[must_use]
     #[inline]
     pub const fn try_from_le_slice(bytes: &[u8]) -> Option<Self> {
-        if bytes.len() / 8 > Self::LIMBS {
+        if bytes.len() > Self::BYTES {
             return None;
         }
 
@@ -331,6 +494,10 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
                 limbs[i] = u64::from_le_bytes(unsafe { *bytes.as_ptr().add(i * 8).cast() });
                 i += 1;
             }
+            // Full bytes do not imply full limbs, e.g. `Uint<63, 1>`.
+            if Self::LIMBS > 0 && limbs[Self::LIMBS - 1] > Self::MASK {
+                return None;
+            }
             return Some(Self::from_limbs(limbs));
         }
 
@@ -401,6 +568,73 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         Some(self.copy_le_bytes_to(buf))
     }
 
+    /// Number of bytes in the little-endian representation with trailing
+    /// zeros removed.
+    ///
+    /// This is the length of [`Self::as_le_bytes_trimmed`] and equal to
+    /// [`Self::byte_len`], but does not require `alloc`.
+    #[must_use]
+    #[inline]
+    pub fn le_bytes_trimmed_len(&self) -> usize {
+        self.byte_len()
+    }
+
+    /// Writes the little-endian representation of the [`Uint`] with trailing
+    /// zeros removed to the given buffer. The buffer must be large enough to
+    /// hold [`Self::le_bytes_trimmed_len`] bytes.
+    ///
+    /// This is the `no_std` counterpart of [`Self::as_le_bytes_trimmed`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough to hold
+    /// [`Self::le_bytes_trimmed_len`] bytes.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written to the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// let mut buf = [0; 32];
+    /// let len = 0x0102_U256.copy_le_bytes_trimmed_to(&mut buf);
+    /// assert_eq!(&buf[..len], &[0x02, 0x01]);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn copy_le_bytes_trimmed_to(&self, buf: &mut [u8]) -> usize {
+        let len = self.le_bytes_trimmed_len();
+        buf[..len]
+            .chunks_mut(8)
+            .zip(self.limbs)
+            .for_each(|(chunk, limb)| {
+                let le = limb.to_le_bytes();
+                chunk.copy_from_slice(&le[..chunk.len()]);
+            });
+        len
+    }
+
+    /// Writes the little-endian representation of the [`Uint`] with trailing
+    /// zeros removed to the given buffer.
+    ///
+    /// # Returns
+    ///
+    /// [`None`], if the buffer is not large enough to hold
+    /// [`Self::le_bytes_trimmed_len`] bytes, and does not modify the buffer.
+    ///
+    /// [`Some`] with the number of bytes written to the buffer.
+    #[inline]
+    pub fn checked_copy_le_bytes_trimmed_to(&self, buf: &mut [u8]) -> Option<usize> {
+        if buf.len() < self.le_bytes_trimmed_len() {
+            return None;
+        }
+
+        Some(self.copy_le_bytes_trimmed_to(buf))
+    }
+
     /// Writes the big-endian representation of the [`Uint`] to the given
     /// buffer. The buffer must be large enough to hold [`Self::BYTES`] bytes.
     ///
@@ -464,6 +698,13 @@ pub const fn nbytes(bits: usize) -> usize {
     (bits + 7) / 8
 }
 
+/// Compile time check of the array length in [`Uint::checked_from_be_bytes`].
+struct ByteWidth<const BITS: usize, const BYTES: usize>;
+
+impl<const BITS: usize, const BYTES: usize> ByteWidth<BITS, BYTES> {
+    const OK: () = assert!(BYTES == nbytes(BITS), "BYTES must be equal to Self::BYTES");
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -541,6 +782,26 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_const_from_slice() {
+        // Both the full width and the generic path are evaluated at compile time.
+        const BE_FULL: Uint<128, 2> = Uint::from_be_slice(&BE);
+        const BE_SHORT: Uint<128, 2> = Uint::from_be_slice(&KBE);
+        const BE_LARGE: Option<Uint<64, 1>> = Uint::try_from_be_slice(&BE);
+        const BE_63: Option<Uint<63, 1>> = Uint::try_from_be_slice(&[0x80, 0, 0, 0, 0, 0, 0, 0]);
+        const LE_FULL: Uint<128, 2> = Uint::from_le_slice(&LE);
+        const LE_SHORT: Uint<128, 2> = Uint::from_le_slice(&KLE);
+        const BYTES: &[u8; 32] = &[0x11; 32];
+        const U256: Uint<256, 4> = Uint::from_be_slice(BYTES);
+        assert_eq!(BE_FULL, N);
+        assert_eq!(BE_SHORT.limbs, K.limbs);
+        assert_eq!(BE_LARGE, None);
+        assert_eq!(BE_63, None);
+        assert_eq!(LE_FULL, N);
+        assert_eq!(LE_SHORT.limbs, K.limbs);
+        assert_eq!(U256.into_limbs(), [0x1111_1111_1111_1111; 4]);
+    }
+
     #[test]
     fn test_from_bytes() {
         assert_eq!(Uint::<0, 0>::from_be_bytes([]), Uint::ZERO);
@@ -567,6 +828,147 @@ mod tests {
         assert_eq!(Uint::from_le_bytes(KLE), K);
     }
 
+    #[test]
+    fn test_checked_from_be_bytes() {
+        const _: () = {
+            assert!(Uint::<0, 0>::checked_from_be_bytes([]).is_some());
+            assert!(Uint::<7, 1>::checked_from_be_bytes([0x80]).is_none());
+        };
+        assert_eq!(Uint::checked_from_be_bytes(BE), Some(N));
+        assert_eq!(Uint::checked_from_be_bytes(KBE), Some(K));
+        assert_eq!(
+            Uint::<12, 1>::checked_from_be_bytes([0x0f, 0xff]),
+            Some(Uint::from(0xfff))
+        );
+        assert_eq!(Uint::<12, 1>::checked_from_be_bytes([0x10, 0x00]), None);
+        assert_eq!(Uint::<12, 1>::checked_from_be_bytes([0xff, 0xff]), None);
+        assert_eq!(Uint::<65, 2>::checked_from_be_bytes([2; 9]), None);
+        assert_eq!(
+            Uint::<63, 1>::checked_from_be_bytes([0x80, 0, 0, 0, 0, 0, 0, 0]),
+            None
+        );
+        assert_eq!(
+            Uint::<63, 1>::try_from_le_slice(&[0, 0, 0, 0, 0, 0, 0, 0x80]),
+            None
+        );
+        assert_eq!(
+            Uint::<65, 2>::checked_from_be_bytes([1, 0, 0, 0, 0, 0, 0, 0, 0]),
+            Some(Uint::from(1) << 64)
+        );
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            const BYTES: usize = nbytes(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let bytes = value.to_be_bytes::<BYTES>();
+                assert_eq!(U::checked_from_be_bytes(bytes), Some(value));
+            });
+            proptest!(|(bytes: [u8; BYTES])| {
+                assert_eq!(U::checked_from_be_bytes(bytes), U::try_from_be_slice(&bytes));
+            });
+        });
+    }
+
+    #[test]
+    #[allow(clippy::absurd_extreme_comparisons)] // From macro.
+    fn test_from_slice_high_bits() {
+        type U12 = Uint<12, 1>;
+        assert_eq!(U12::try_from_be_slice(&[0x0f, 0xff]), Some(U12::from(0xfff)));
+        assert_eq!(U12::try_from_be_slice(&[0x10, 0x00]), None);
+        assert_eq!(U12::try_from_be_slice(&[0xff, 0xff]), None);
+        assert_eq!(U12::try_from_le_slice(&[0xff, 0x0f]), Some(U12::from(0xfff)));
+        assert_eq!(U12::try_from_le_slice(&[0x00, 0x10]), None);
+        assert_eq!(U12::try_from_le_slice(&[0xff, 0xff]), None);
+        // Slices longer than `BYTES` are rejected in both byte orders.
+        assert_eq!(U12::try_from_le_slice(&[0xff, 0x0f, 0]), None);
+        assert_eq!(U12::try_from_be_slice(&[0, 0x0f, 0xff]), None);
+        // Longer than the limbs used to panic instead of returning `None`.
+        assert_eq!(U12::try_from_le_slice(&[0; 9]), None);
+        assert_eq!(Uint::<72, 2>::try_from_le_slice(&[0; 17]), None);
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(bytes: Vec<u8>)| {
+                let mut reversed = bytes.clone();
+                reversed.reverse();
+                let le = U::try_from_le_slice(&bytes);
+                assert_eq!(U::try_from_be_slice(&reversed), le);
+                let bit_len = bytes
+                    .iter()
+                    .rposition(|&b| b != 0)
+                    .map_or(0, |i| i * 8 + 8 - bytes[i].leading_zeros() as usize);
+                assert_eq!(le.is_some(), bit_len <= BITS && bytes.len() <= U::BYTES);
+            });
+        });
+    }
+
+    #[test]
+    fn test_from_be_slice_with_len() {
+        type U = Uint<72, 2>;
+        assert_eq!(U::from_be_slice_with_len(&KBE), Some((K, 9)));
+        assert_eq!(
+            U::from_be_slice_with_len(&[0, 0, 0x12, 0x34]),
+            Some((U::from(0x1234), 2))
+        );
+        assert_eq!(U::from_be_slice_with_len(&[]), Some((U::ZERO, 0)));
+        assert_eq!(U::from_be_slice_with_len(&[0; 9]), Some((U::ZERO, 0)));
+        assert_eq!(U::from_be_slice_with_len(&[1; 10]), None);
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                let bytes = value.to_be_bytes_vec();
+                let (parsed, len) = Uint::<BITS, LIMBS>::from_be_slice_with_len(&bytes).unwrap();
+                assert_eq!(parsed, value);
+                assert_eq!(len, value.to_be_bytes_trimmed_vec().len());
+                assert!(bytes[..bytes.len() - len].iter().all(|&b| b == 0));
+            });
+        });
+    }
+
+    #[test]
+    fn test_signed_bytes() {
+        type U = Uint<256, 4>;
+        assert_eq!(U::from_be_bytes_signed(&[]), (U::ZERO, false));
+        assert_eq!(U::from_be_bytes_signed(&[0xff; 40]), (U::MAX, true));
+        assert_eq!(U::ZERO.to_be_bytes_signed(), [0]);
+        assert_eq!(U::MAX.to_be_bytes_signed(), [0xff]);
+        assert_eq!(
+            Uint::<0, 0>::from_be_bytes_signed(&[0xff]),
+            (Uint::ZERO, true)
+        );
+        let min = i64::MIN.to_be_bytes();
+        assert_eq!(U::from_be_bytes_signed(&min), (-U::from(1_u64 << 63), true));
+        assert_eq!(U::from_be_bytes_signed(&min).0.to_be_bytes_signed(), min);
+        assert_eq!(
+            Uint::<12, 1>::from_be_bytes_signed(&[0x80]),
+            (Uint::from(0xf80), true)
+        );
+        assert_eq!(Uint::<12, 1>::from(0xf80).to_be_bytes_signed(), [0x80]);
+        assert_eq!(Uint::<12, 1>::from(0x800).to_be_bytes_signed(), [
+            0xf8, 0x00
+        ]);
+        proptest!(|(value: i128)| {
+            let expected = if value < 0 {
+                -U::from(value.unsigned_abs())
+            } else {
+                U::from(value)
+            };
+            let bytes = value.to_be_bytes();
+            assert_eq!(U::from_be_bytes_signed(&bytes), (expected, value < 0));
+            let trimmed = expected.to_be_bytes_signed();
+            assert_eq!(&bytes[16 - trimmed.len()..], &trimmed[..]);
+        });
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let bytes = value.to_be_bytes_signed();
+                let negative = BITS.checked_sub(1).map_or(false, |top| value.bit(top));
+                assert_eq!(U::from_be_bytes_signed(&bytes), (value, negative));
+            });
+        });
+    }
+
     #[test]
     fn test_to_bytes() {
         assert_eq!(Uint::<0, 0>::ZERO.to_le_bytes(), [0_u8; 0]);
@@ -633,4 +1035,30 @@ mod tests {
             });
         });
     }
+
+    #[test]
+    fn copy_trimmed_to() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            const BYTES: usize = nbytes(BITS);
+            proptest!(|(value: Uint<BITS, LIMBS>)|{
+                let trimmed = value.as_le_bytes_trimmed();
+                assert_eq!(value.le_bytes_trimmed_len(), trimmed.len());
+
+                let mut buf = [0xff; BYTES];
+                let len = value.copy_le_bytes_trimmed_to(&mut buf);
+                assert_eq!(&buf[..len], &trimmed[..]);
+                assert!(buf[len..].iter().all(|&b| b == 0xff), "wrote past trimmed length");
+
+                let mut buf = [0; BYTES];
+                assert_eq!(value.checked_copy_le_bytes_trimmed_to(&mut buf[..len]), Some(len));
+                assert_eq!(&buf[..len], &trimmed[..]);
+                if len != 0 {
+                    let mut buf = [0; BYTES];
+                    assert_eq!(value.checked_copy_le_bytes_trimmed_to(&mut buf[..len - 1]), None);
+                    assert_eq!(buf, [0; BYTES], "buffer was modified");
+                }
+            });
+        });
+    }
 }
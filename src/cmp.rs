@@ -22,11 +22,101 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     pub fn is_zero(&self) -> bool {
         *self == Self::ZERO
     }
+
+    /// Const version of [`Ord::cmp`].
+    #[inline]
+    const fn const_cmp(&self, other: &Self) -> Ordering {
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+            if self.limbs[i] != other.limbs[i] {
+                return if self.limbs[i] < other.limbs[i] {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                };
+            }
+        }
+        Ordering::Equal
+    }
+
+    /// Const version of [`Ord::min`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// const MIN: U64 = 3_U64.const_min(5_U64);
+    /// assert_eq!(MIN, 3_U64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn const_min(self, other: Self) -> Self {
+        match self.const_cmp(&other) {
+            Ordering::Greater => other,
+            _ => self,
+        }
+    }
+
+    /// Const version of [`Ord::max`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// const MAX: U64 = 3_U64.const_max(5_U64);
+    /// assert_eq!(MAX, 5_U64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn const_max(self, other: Self) -> Self {
+        match self.const_cmp(&other) {
+            Ordering::Greater => self,
+            _ => other,
+        }
+    }
+
+    /// Const version of [`Ord::clamp`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// const CLAMPED: U64 = 7_U64.const_clamp(3_U64, 5_U64);
+    /// assert_eq!(CLAMPED, 5_U64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn const_clamp(self, min: Self, max: Self) -> Self {
+        assert!(
+            !matches!(min.const_cmp(&max), Ordering::Greater),
+            "min must not be greater than max"
+        );
+        if matches!(self.const_cmp(&min), Ordering::Less) {
+            min
+        } else if matches!(self.const_cmp(&max), Ordering::Greater) {
+            max
+        } else {
+            self
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::Uint;
+    use crate::{aliases::U128, const_for, nlimbs, Uint};
+    use proptest::proptest;
 
     #[test]
     fn test_is_zero() {
@@ -39,4 +129,35 @@ mod tests {
         assert!(!Uint::<7, 1>::from_limbs([1]).is_zero());
         assert!(!Uint::<64, 1>::from_limbs([1]).is_zero());
     }
+
+    #[test]
+    fn test_const_min_max_clamp() {
+        const A: U128 = U128::from_limbs([0, 1]);
+        const B: U128 = U128::from_limbs([u64::MAX, 0]);
+        const _: () = {
+            assert!(A.const_min(B).as_limbs()[0] == u64::MAX);
+            assert!(A.const_max(B).as_limbs()[1] == 1);
+            assert!(B.const_max(A).as_limbs()[1] == 1);
+            assert!(U128::MAX.const_clamp(B, A).as_limbs()[1] == 1);
+            assert!(U128::ZERO.const_clamp(B, A).as_limbs()[0] == u64::MAX);
+            assert!(B.const_clamp(B, A).as_limbs()[0] == u64::MAX);
+        };
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U, c: U)| {
+                assert_eq!(a.const_cmp(&b), a.cmp(&b));
+                assert_eq!(a.const_min(b), a.min(b));
+                assert_eq!(a.const_max(b), a.max(b));
+                let (lo, hi) = (b.min(c), b.max(c));
+                assert_eq!(a.const_clamp(lo, hi), a.clamp(lo, hi));
+            });
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "min must not be greater than max")]
+    fn test_const_clamp_invalid() {
+        let _ = U128::ZERO.const_clamp(U128::from(2), U128::from(1));
+    }
 }
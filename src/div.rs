@@ -3,25 +3,71 @@ use core::ops::{Div, DivAssign, Rem, RemAssign};
 
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Computes `self / rhs`, returning [`None`] if `rhs == 0`.
+    ///
+    /// Unlike the [`Div`] operator this never panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(7_U256.checked_div(2_U256), Some(3_U256));
+    /// assert_eq!(7_U256.checked_div(0_U256), None);
+    /// # }
+    /// ```
     #[inline]
     #[must_use]
-    #[allow(clippy::missing_const_for_fn)] // False positive
     pub fn checked_div(self, rhs: Self) -> Option<Self> {
         if rhs.is_zero() {
             return None;
         }
-        Some(self.div(rhs))
+        Some(self.div_rem(rhs).0)
     }
 
     /// Computes `self % rhs`, returning [`None`] if `rhs == 0`.
+    ///
+    /// Unlike the [`Rem`] operator this never panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(7_U256.checked_rem(2_U256), Some(1_U256));
+    /// assert_eq!(7_U256.checked_rem(0_U256), None);
+    /// # }
+    /// ```
     #[inline]
     #[must_use]
-    #[allow(clippy::missing_const_for_fn)] // False positive
     pub fn checked_rem(self, rhs: Self) -> Option<Self> {
         if rhs.is_zero() {
             return None;
         }
-        Some(self.rem(rhs))
+        Some(self.panicking_rem(rhs))
+    }
+
+    /// Computes `self / rhs` and `self % rhs`, returning [`None`] if
+    /// `rhs == 0`.
+    ///
+    /// Unlike [`Self::div_rem`] this never panics, and unlike separate calls
+    /// to [`Self::checked_div`] and [`Self::checked_rem`] it divides once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(7_U256.checked_div_rem(2_U256), Some((3_U256, 1_U256)));
+    /// assert_eq!(7_U256.checked_div_rem(0_U256), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_div_rem(self, rhs: Self) -> Option<(Self, Self)> {
+        if rhs.is_zero() {
+            return None;
+        }
+        Some(self.div_rem(rhs))
     }
 
     /// Computes `self / rhs` rounding up.
@@ -54,33 +100,181 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         (self, rhs)
     }
 
-    /// Computes `self / rhs` rounding down.
+    /// Computes `self / rhs` and `self % rhs` for a single limb divisor.
+    ///
+    /// Faster than [`Self::div_rem`] as it avoids constructing a full
+    /// [`Uint`] divisor and the remainder is returned as a `u64`.
     ///
     /// # Panics
     ///
     /// Panics if `rhs == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(
+    ///     0x1_0000_0000_0000_0007_U256.div_rem_u64(2),
+    ///     (0x8000_0000_0000_0003_U256, 1)
+    /// );
+    /// # }
+    /// ```
     #[inline]
     #[must_use]
     #[track_caller]
-    pub fn wrapping_div(self, rhs: Self) -> Self {
-        self.div_rem(rhs).0
+    pub fn div_rem_u64(mut self, rhs: u64) -> (Self, u64) {
+        assert!(rhs != 0, "Divisor is zero");
+        let Some(top) = self.limbs.iter().rposition(|&limb| limb != 0) else {
+            return (self, 0);
+        };
+        let remainder = if top == 0 {
+            let remainder = self.limbs[0] % rhs;
+            self.limbs[0] /= rhs;
+            remainder
+        } else {
+            algorithms::div::div_nx1(&mut self.limbs[..=top], rhs)
+        };
+        (self, remainder)
     }
 
-    /// Computes `self % rhs`.
+    /// Computes `self % m` for a single limb modulus.
+    ///
+    /// This makes a single reciprocal based pass over the limbs without
+    /// computing the quotient, which makes it cheaper than
+    /// [`Self::div_rem_u64`] for hashing into buckets or trial division.
     ///
     /// # Panics
     ///
-    /// Panics if `rhs == 0`.
+    /// Panics if `m == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(0x1_0000_0000_0000_0007_U256.mod_u64(10), 3);
+    /// assert_eq!(U256::MAX.mod_u64(u64::MAX), 0);
+    /// # }
+    /// ```
     #[inline]
     #[must_use]
     #[track_caller]
+    pub fn mod_u64(self, m: u64) -> u64 {
+        assert!(m != 0, "Divisor is zero");
+        algorithms::div::rem_nx1(&self.limbs, m)
+    }
+
+    /// Computes `self % m` for a single limb modulus, returning [`None`] if
+    /// `m == 0`.
+    ///
+    /// This is the non-panicking version of [`Self::mod_u64`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(0x1_0000_0000_0000_0007_U256.checked_rem_u64(10), Some(3));
+    /// assert_eq!(7_U256.checked_rem_u64(0), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_rem_u64(self, m: u64) -> Option<u64> {
+        if m == 0 {
+            return None;
+        }
+        Some(self.mod_u64(m))
+    }
+
+    /// Returns `true` if `self` is an integer multiple of `rhs`.
+    ///
+    /// Like [`u64::is_multiple_of`], zero is only a multiple of itself, so this
+    /// does not panic when `rhs == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert!(6_U256.is_multiple_of(3_U256));
+    /// assert!(!7_U256.is_multiple_of(3_U256));
+    /// assert!(0_U256.is_multiple_of(0_U256));
+    /// assert!(!7_U256.is_multiple_of(0_U256));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_multiple_of(self, rhs: Self) -> bool {
+        if rhs.is_zero() {
+            return self.is_zero();
+        }
+        self.panicking_rem(rhs).is_zero()
+    }
+
+    /// Returns `true` if `self` is an integer multiple of the single limb
+    /// `rhs`, see [`Self::is_multiple_of`].
+    ///
+    /// Uses [`Self::mod_u64`], which is faster than the general case.
+    #[inline]
+    #[must_use]
+    pub fn is_multiple_of_u64(self, rhs: u64) -> bool {
+        if rhs == 0 {
+            return self.is_zero();
+        }
+        self.mod_u64(rhs) == 0
+    }
+
+    /// Computes `self / rhs` rounding down.
+    ///
+    /// Division by zero does not panic but returns [`Self::MAX`], matching the
+    /// RISC-V `DIVU` convention. Use [`Self::checked_div`] to detect it.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        if rhs.is_zero() {
+            return Self::MAX;
+        }
+        self.div_rem(rhs).0
+    }
+
+    /// Computes `self % rhs`.
+    ///
+    /// Division by zero does not panic but returns `self`, matching the RISC-V
+    /// `REMU` convention. Use [`Self::checked_rem`] to detect it.
+    #[inline]
+    #[must_use]
     pub fn wrapping_rem(self, rhs: Self) -> Self {
-        self.div_rem(rhs).1
+        if rhs.is_zero() {
+            return self;
+        }
+        self.panicking_rem(rhs)
+    }
+
+    /// Computes `self / rhs`, panicking on division by zero like the primitive
+    /// integer types do. Used for the [`Div`] operator.
+    #[inline]
+    #[track_caller]
+    fn panicking_div(self, rhs: Self) -> Self {
+        self.div_rem(rhs).0
+    }
+
+    /// Computes `self % rhs`, panicking on division by zero. Used for the
+    /// [`Rem`] operator.
+    ///
+    /// Same as `self.div_rem(rhs).1`, but faster for divisors of one or two
+    /// limbs as the quotient is not stored.
+    #[inline]
+    #[track_caller]
+    fn panicking_rem(mut self, mut rhs: Self) -> Self {
+        algorithms::rem(&mut self.limbs, &mut rhs.limbs);
+        rhs
     }
 }
 
-impl_bin_op!(Div, div, DivAssign, div_assign, wrapping_div);
-impl_bin_op!(Rem, rem, RemAssign, rem_assign, wrapping_rem);
+impl_bin_op!(Div, div, DivAssign, div_assign, panicking_div);
+impl_bin_op!(Rem, rem, RemAssign, rem_assign, panicking_rem);
 
 #[cfg(test)]
 mod tests {
@@ -88,6 +282,96 @@ mod tests {
     use crate::{const_for, nlimbs};
     use proptest::{prop_assume, proptest};
 
+    #[test]
+    fn test_div_rem_u64() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U, d: u64, shift in 0..64_u32)| {
+                for d in [d >> shift, 1, 2, 10, u64::MAX] {
+                    if d == 0 {
+                        continue;
+                    }
+                    let (q, r) = n.div_rem_u64(d);
+                    assert!(r < d);
+                    match U::try_from(d) {
+                        Ok(d) => assert_eq!((q, U::from(r)), n.div_rem(d)),
+                        // Divisor exceeds `U::MAX` so `n < d`.
+                        Err(_) => assert_eq!((q, r), (U::ZERO, n.to::<u64>())),
+                    }
+                }
+            });
+        });
+        assert_eq!(Uint::<0, 0>::ZERO.div_rem_u64(3), (Uint::ZERO, 0));
+    }
+
+    #[test]
+    fn test_mod_u64() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U, d: u64, shift in 0..64_u32)| {
+                for d in [d >> shift, 1, 3, 1 << 63, u64::MAX] {
+                    if d == 0 {
+                        continue;
+                    }
+                    let r = n.mod_u64(d);
+                    assert_eq!(r, n.div_rem_u64(d).1);
+                    if let Ok(d) = U::try_from(d) {
+                        assert_eq!(U::from(r), n % d);
+                    }
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_checked_rem_u64() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U, d: u64)| {
+                assert_eq!(n.checked_rem_u64(0), None);
+                if d != 0 {
+                    assert_eq!(n.checked_rem_u64(d), Some(n.mod_u64(d)));
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_is_multiple_of() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U, d: U, shift in 0..=BITS, k: U)| {
+                let d = d >> shift;
+                let expected = n.checked_rem(d).map_or_else(|| n.is_zero(), |r| r.is_zero());
+                assert_eq!(n.is_multiple_of(d), expected);
+                if let Some(m) = d.checked_mul(k) {
+                    assert!(m.is_multiple_of(d));
+                }
+            });
+            proptest!(|(n: U, d: u64)| {
+                let expected = U::try_from(d).map_or_else(|_| n.is_zero(), |d| n.is_multiple_of(d));
+                assert_eq!(n.is_multiple_of_u64(d), expected);
+                assert_eq!(n.is_multiple_of_u64(0), n.is_zero());
+            });
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Divisor is zero")]
+    fn test_mod_u64_zero() {
+        let _ = Uint::<128, 2>::from(1).mod_u64(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Divisor is zero")]
+    fn test_div_rem_u64_zero() {
+        let _ = Uint::<128, 2>::from(1).div_rem_u64(0);
+    }
+
     #[test]
     fn test_div_ceil() {
         const_for!(BITS in NON_ZERO {
@@ -107,6 +391,61 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_checked() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U, d: U)| {
+                if d == U::ZERO {
+                    assert_eq!(n.checked_div(d), None);
+                    assert_eq!(n.checked_rem(d), None);
+                    assert_eq!(n.checked_div_rem(d), None);
+                } else {
+                    assert_eq!(n.checked_div(d), Some(n / d));
+                    assert_eq!(n.checked_rem(d), Some(n % d));
+                    let (q, r) = n.checked_div_rem(d).unwrap();
+                    assert_eq!((q, r), (n / d, n % d));
+                    assert!(r < d);
+                    assert_eq!(q * d + r, n);
+                }
+            });
+            assert_eq!(U::MAX.checked_div(U::MAX), Some(U::from(1)));
+            assert_eq!(U::MAX.checked_rem(U::MAX), Some(U::ZERO));
+            assert_eq!(U::MAX.checked_div_rem(U::MAX), Some((U::from(1), U::ZERO)));
+            assert_eq!(U::MAX.checked_div(U::ZERO), None);
+            assert_eq!(U::MAX.checked_rem(U::ZERO), None);
+            assert_eq!(U::MAX.checked_div_rem(U::ZERO), None);
+        });
+    }
+
+    #[test]
+    fn test_div_by_zero() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U)| {
+                assert_eq!(n.wrapping_div(U::ZERO), U::MAX);
+                assert_eq!(n.wrapping_rem(U::ZERO), n);
+                assert_eq!(n.checked_div(U::ZERO), None);
+                assert_eq!(n.checked_rem(U::ZERO), None);
+                assert_eq!(n.checked_div_rem(U::ZERO), None);
+            });
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Divisor is zero")]
+    fn test_div_operator_by_zero() {
+        let _ = Uint::<64, 1>::from(1) / Uint::ZERO;
+    }
+
+    #[test]
+    #[should_panic(expected = "Divisor is zero")]
+    fn test_rem_operator_by_zero() {
+        let _ = Uint::<64, 1>::from(1) % Uint::ZERO;
+    }
+
     #[test]
     fn test_divrem() {
         const_for!(BITS in NON_ZERO {
@@ -139,4 +478,19 @@ mod tests {
             });
         });
     }
+
+    #[test]
+    fn test_rem() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U, d: U, shift in 0..BITS)| {
+                let d = d >> shift;
+                prop_assume!(d != U::ZERO);
+                assert_eq!(n % d, n.div_rem(d).1);
+                assert_eq!(n.checked_rem(d), Some(n.div_rem(d).1));
+                assert_eq!(n.wrapping_rem(d), n.div_rem(d).1);
+            });
+        });
+    }
 }
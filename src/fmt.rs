@@ -50,6 +50,9 @@ mod base {
 }
 use base::Base;
 
+// Digits are produced in chunks of `Base::MAX`, so a single big integer
+// division yields `Base::WIDTH` characters. Every chunk except the leading one
+// is zero padded to the full width.
 macro_rules! write_digits {
     ($self:expr, $f:expr; $base:ty, $base_char:literal) => {
         if LIMBS == 0 || $self.is_zero() {
@@ -213,4 +216,32 @@ mod tests {
             prop_assert_eq!(format!("{n:#X}"), format!("{value:#X}"));
         });
     }
+
+    #[test]
+    fn test_chunk_boundaries() {
+        type U = Uint<256, 4>;
+        let chunk = U::from(base::Decimal::MAX);
+        let values = [
+            chunk - U::from(1),
+            chunk,
+            chunk + U::from(1),
+            chunk * chunk - U::from(1),
+            chunk * chunk,
+            chunk * chunk + U::from(5),
+            chunk * chunk * chunk + chunk,
+            U::MAX,
+        ];
+        for value in values {
+            let digits = value
+                .to_base_be(10)
+                .map(|digit| char::from_digit(u32::try_from(digit).unwrap(), 10).unwrap())
+                .collect::<String>();
+            assert_eq!(value.to_string(), digits);
+        }
+        assert_eq!((chunk * chunk).to_string(), format!("1{}", "0".repeat(38)));
+        assert_eq!(
+            (chunk * chunk + U::from(5)).to_string(),
+            format!("1{}5", "0".repeat(37))
+        );
+    }
 }
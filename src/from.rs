@@ -72,6 +72,37 @@ impl<T> fmt::Display for ToUintError<T> {
     }
 }
 
+/// Error for [`TryFrom<&[u64]>`][TryFrom] for [`Uint`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum FromLimbsError<T> {
+    /// The slice does not have exactly the number of limbs of the Uint.
+    ///
+    /// `.0` is `BITS` and `.1` is the length of the slice.
+    InvalidLength(usize, usize),
+
+    /// Value is too large to fit the Uint.
+    ///
+    /// `.0` is `BITS` and `.1` is the wrapped value.
+    ValueTooLarge(usize, T),
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> std::error::Error for FromLimbsError<T> {}
+
+impl<T> fmt::Display for FromLimbsError<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(bits, len) => write!(
+                f,
+                "Uint<{bits}> requires {} limbs, got {len}",
+                crate::nlimbs(*bits)
+            ),
+            Self::ValueTooLarge(bits, _) => write!(f, "Value is too large for Uint<{bits}>"),
+        }
+    }
+}
+
 /// Error for [`TryFrom<Uint>`][TryFrom].
 #[allow(clippy::derive_partial_eq_without_eq)] // False positive
 #[allow(clippy::module_name_repetitions)]
@@ -211,6 +242,63 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         }
     }
 
+    /// Converts to a [`Uint`] of width `B2`, keeping the low `B2` bits.
+    ///
+    /// Higher bits are discarded. If `B2 > BITS` the value is zero-extended.
+    /// This is [`Uint::wrapping_from`] with a more explicit name.
+    ///
+    /// It is a compile time error if `L2` does not match `B2`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(0x1234_U16.truncate::<8, 1>(), 0x34_U8);
+    /// assert_eq!(0x1234_U16.truncate::<32, 1>(), 0x1234_U32);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn truncate<const B2: usize, const L2: usize>(self) -> Uint<B2, L2> {
+        let () = ResizeWidths::<BITS, B2, L2>::LIMBS_OK;
+        let mut limbs = [0; L2];
+        let mut i = 0;
+        while i < L2 && i < LIMBS {
+            limbs[i] = self.limbs[i];
+            i += 1;
+        }
+        if L2 > 0 {
+            limbs[L2 - 1] &= Uint::<B2, L2>::MASK;
+        }
+        Uint::from_limbs(limbs)
+    }
+
+    /// Zero-extends to a [`Uint`] of width `B2 >= BITS`.
+    ///
+    /// This never loses information. It is a compile time error if `B2 < BITS`
+    /// or `L2` does not match `B2`:
+    ///
+    /// ```compile_fail
+    /// # use ruint::aliases::*;
+    /// let _ = U128::MAX.extend::<64, 1>();
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(0x1234_U16.extend::<256, 4>(), 0x1234_U256);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn extend<const B2: usize, const L2: usize>(self) -> Uint<B2, L2> {
+        let () = ResizeWidths::<BITS, B2, L2>::EXTEND_OK;
+        self.truncate()
+    }
+
     /// # Panics
     ///
     /// Panics if the conversion fails, for example if the value is too large
@@ -278,6 +366,62 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         }
     }
 
+    /// Returns the low 64 bits, like `as_u64` in `primitive-types`.
+    ///
+    /// Values that do not fit are truncated in release builds, like an `as`
+    /// cast. In debug builds this panics instead, to catch unintended
+    /// truncation. Use [`Self::wrapping_to`] or [`Self::to`] to get one of
+    /// the two behaviours regardless of build profile.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the value does not fit in a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(0xcafe_U256.as_u64(), 0xcafe_u64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn as_u64(&self) -> u64 {
+        debug_assert!(self.bit_len() <= 64, "Value too large for u64");
+        self.wrapping_to()
+    }
+
+    /// Returns the low bits as a `usize`, see [`Self::as_u64`].
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the value does not fit in a `usize`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn as_usize(&self) -> usize {
+        debug_assert!(
+            self.bit_len() <= usize::BITS as usize,
+            "Value too large for usize"
+        );
+        self.wrapping_to()
+    }
+
+    /// Returns the low 128 bits, see [`Self::as_u64`].
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the value does not fit in a `u128`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn as_u128(&self) -> u128 {
+        debug_assert!(self.bit_len() <= 128, "Value too large for u128");
+        self.wrapping_to()
+    }
+
     /// Construct a new [`Uint`] from a potentially different sized [`Uint`].
     ///
     /// # Panics
@@ -479,6 +623,30 @@ impl_from_signed_int!(i64, u64);
 impl_from_signed_int!(i128, u128);
 impl_from_signed_int!(isize, usize);
 
+/// Converts a little-endian slice of exactly `LIMBS` limbs.
+///
+/// Unlike [`Uint::from_limbs_slice`] this does not panic and rejects slices of
+/// any other length, which makes it suitable for untrusted input.
+///
+/// # Errors
+///
+/// * [`FromLimbsError::InvalidLength`] if the slice length is not `LIMBS`.
+/// * [`FromLimbsError::ValueTooLarge`] if the high bits above `BITS` are set.
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&[u64]> for Uint<BITS, LIMBS> {
+    type Error = FromLimbsError<Self>;
+
+    #[inline]
+    fn try_from(value: &[u64]) -> Result<Self, Self::Error> {
+        if value.len() != LIMBS {
+            return Err(FromLimbsError::InvalidLength(BITS, value.len()));
+        }
+        match Self::overflowing_from_limbs_slice(value) {
+            (n, false) => Ok(n),
+            (n, true) => Err(FromLimbsError::ValueTooLarge(BITS, n)),
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 impl<const BITS: usize, const LIMBS: usize> TryFrom<f64> for Uint<BITS, LIMBS> {
     type Error = ToUintError<Self>;
@@ -513,8 +681,115 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<f64> for Uint<BITS, LIMBS> {
         assert!(value.is_normal());
 
         // Add offset to round to nearest integer.
-        let value = value + 0.5;
+        Self::from_normal_f64_trunc(value + 0.5)
+    }
+}
+
+/// Compile time checks of the widths in [`Uint::truncate`] and
+/// [`Uint::extend`].
+struct ResizeWidths<const BITS: usize, const B2: usize, const L2: usize>;
 
+impl<const BITS: usize, const B2: usize, const L2: usize> ResizeWidths<BITS, B2, L2> {
+    const LIMBS_OK: () = assert!(L2 == crate::nlimbs(B2), "L2 must be nlimbs(B2)");
+    const EXTEND_OK: () = assert!(B2 >= BITS, "extend target must not be narrower than BITS");
+}
+
+/// Rounding mode for [`Uint::from_f64_with_mode`].
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundMode {
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round towards zero.
+    Trunc,
+    /// Round to the nearest integer, with ties going to the even one.
+    NearestEven,
+}
+
+#[cfg(feature = "std")]
+impl RoundMode {
+    fn round(self, value: f64) -> f64 {
+        match self {
+            Self::Floor => value.floor(),
+            Self::Ceil => value.ceil(),
+            Self::Trunc => value.trunc(),
+            Self::NearestEven => {
+                // `f64::round_ties_even` requires Rust 1.77.
+                let rounded = value.round();
+                #[allow(clippy::float_cmp)] // Exact tie detection is intended.
+                if (rounded - value).abs() == 0.5 {
+                    2.0 * (value / 2.0).round()
+                } else {
+                    rounded
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Converts a floating point value to [`Uint`] using the given rounding
+    /// mode.
+    ///
+    /// Unlike [`TryFrom<f64>`], which rounds to nearest with ties away from
+    /// zero, this lets the caller pick the direction of rounding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ToUintError::NotANumber`] for NaN, and
+    /// [`ToUintError::ValueNegative`] or [`ToUintError::ValueTooLarge`] if the
+    /// *rounded* value is out of range. Note that for example `-0.5` rounds
+    /// to zero with [`RoundMode::Ceil`] and is accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, RoundMode, aliases::U64};
+    /// # uint!{
+    /// assert_eq!(U64::from_f64_with_mode(2.5, RoundMode::Floor), Ok(2_U64));
+    /// assert_eq!(U64::from_f64_with_mode(2.5, RoundMode::Ceil), Ok(3_U64));
+    /// assert_eq!(
+    ///     U64::from_f64_with_mode(2.5, RoundMode::NearestEven),
+    ///     Ok(2_U64)
+    /// );
+    /// assert_eq!(U64::from_f64_with_mode(-0.5, RoundMode::Trunc), Ok(0_U64));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn from_f64_with_mode(value: f64, mode: RoundMode) -> Result<Self, ToUintError<Self>> {
+        if value.is_nan() {
+            return Err(ToUintError::NotANumber(BITS));
+        }
+        let value = mode.round(value);
+        if value < 0.0 {
+            let wrapped = match Self::from_f64_with_mode(-value, RoundMode::Trunc) {
+                Ok(n) | Err(ToUintError::ValueTooLarge(_, n)) => n,
+                _ => Self::ZERO,
+            }
+            .wrapping_neg();
+            return Err(ToUintError::ValueNegative(BITS, wrapped));
+        }
+        #[allow(clippy::cast_precision_loss)] // BITS is small-ish
+        let modulus = (Self::BITS as f64).exp2();
+        if value >= modulus {
+            let wrapped = match Self::from_f64_with_mode(value % modulus, RoundMode::Trunc) {
+                Ok(n) | Err(ToUintError::ValueTooLarge(_, n)) => n,
+                _ => Self::ZERO,
+            };
+            return Err(ToUintError::ValueTooLarge(BITS, wrapped)); // Wrapping
+        }
+        if value < 1.0 {
+            return Ok(Self::ZERO);
+        }
+        Self::from_normal_f64_trunc(value)
+    }
+
+    /// Converts a normal `f64` value `>= 1.0` to [`Uint`], rounding towards
+    /// zero.
+    fn from_normal_f64_trunc(value: f64) -> Result<Self, ToUintError<Self>> {
         // Parse IEEE-754 double
         // Sign should be zero, exponent should be >= 0.
         let bits = value.to_bits();
@@ -561,6 +836,11 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<f32> for Uint<BITS, LIMBS> {
 }
 
 // Convert Uint to integer types
+//
+// Infallible `From<Uint<64, 1>> for u64` and `From<Uint<128, 2>> for u128` are
+// not possible: through the blanket `impl<T, U: Into<T>> TryFrom<U> for T` they
+// would conflict with the generic `TryFrom` impls below (E0119). For these
+// exact widths the `TryFrom` conversions never fail.
 
 // Required because a generic rule violates the orphan rule
 macro_rules! to_value_to_ref {
@@ -715,10 +995,51 @@ impl<const BITS: usize, const LIMBS: usize> From<&Uint<BITS, LIMBS>> for f64 {
     }
 }
 
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Converts to `f64` if the value is exactly representable.
+    ///
+    /// Returns [`None`] if the conversion would lose precision, i.e. if the
+    /// significant bits of the value do not fit the 53 bit mantissa or the
+    /// value is too large for an `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// # uint!{
+    /// assert_eq!(3_U256.to_f64_exact(), Some(3.0));
+    /// assert_eq!(
+    ///     (3_U256 << 200_usize).to_f64_exact(),
+    ///     Some(3.0 * 2.0_f64.powi(200))
+    /// );
+    /// assert_eq!(((1_U256 << 53_usize) + 1_U256).to_f64_exact(), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_f64_exact(&self) -> Option<f64> {
+        let bit_len = self.bit_len();
+        if bit_len == 0 {
+            return Some(0.0);
+        }
+        let trailing_zeros = self.trailing_zeros();
+        if bit_len - trailing_zeros > 53 || bit_len > 1024 {
+            return None;
+        }
+        // At most 53 significant bits, so the conversion to f64 is exact.
+        #[allow(clippy::cast_precision_loss)]
+        let mantissa = (*self >> trailing_zeros).as_limbs()[0] as f64;
+        // Exact power of two 2^trailing_zeros, with trailing_zeros <= 1023.
+        let scale = f64::from_bits(((trailing_zeros + 1023) as u64) << 52);
+        Some(mantissa * scale)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::{const_for, nlimbs};
+    use proptest::proptest;
 
     #[test]
     fn test_u64() {
@@ -734,6 +1055,108 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_limbs_slice() {
+        type U = Uint<100, 2>;
+        // Short.
+        assert_eq!(U::try_from(&[1][..]), Err(FromLimbsError::InvalidLength(100, 1)));
+        // Exact.
+        assert_eq!(U::try_from(&[1, 2][..]), Ok(U::from_limbs([1, 2])));
+        // Long, even with zero excess limbs.
+        assert_eq!(
+            U::try_from(&[1, 2, 0][..]),
+            Err(FromLimbsError::InvalidLength(100, 3))
+        );
+        // Over-mask.
+        assert_eq!(
+            U::try_from(&[1, 1 << 36][..]),
+            Err(FromLimbsError::ValueTooLarge(100, U::from(1)))
+        );
+        assert_eq!(Uint::<0, 0>::try_from(&[][..]), Ok(Uint::ZERO));
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                assert_eq!(U::try_from(&value.as_limbs()[..]), Ok(value));
+            });
+        });
+    }
+
+    #[test]
+    fn test_as_int() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                if value.bit_len() <= 64 {
+                    assert_eq!(value.as_u64(), value.to::<u64>());
+                    assert_eq!(value.as_usize(), value.to::<usize>());
+                }
+                if value.bit_len() <= 128 {
+                    assert_eq!(value.as_u128(), value.to::<u128>());
+                }
+            });
+        });
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Value too large for u64")]
+    fn test_as_u64_debug_panic() {
+        let _ = Uint::<128, 2>::from_limbs([0, 1]).as_u64();
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Value too large for u128")]
+    fn test_as_u128_debug_panic() {
+        let _ = Uint::<256, 4>::MAX.as_u128();
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn test_as_int_release_truncate() {
+        let value = Uint::<256, 4>::from_limbs([1, 2, 3, 4]);
+        assert_eq!(value.as_u64(), 1);
+        assert_eq!(value.as_usize(), 1);
+        assert_eq!(value.as_u128(), 1 | (2 << 64));
+    }
+
+    #[test]
+    fn test_exact_width_to_int() {
+        proptest!(|(value: u64)| {
+            let uint = Uint::<64, 1>::from(value);
+            assert_eq!(u64::try_from(uint), Ok(value));
+            assert_eq!(u64::try_from(&uint), Ok(value));
+        });
+        proptest!(|(value: u128)| {
+            let uint = Uint::<128, 2>::from(value);
+            assert_eq!(u128::try_from(uint), Ok(value));
+            assert_eq!(u128::try_from(&uint), Ok(value));
+        });
+        assert_eq!(u64::try_from(Uint::<64, 1>::MAX), Ok(u64::MAX));
+        assert_eq!(u128::try_from(Uint::<128, 2>::MAX), Ok(u128::MAX));
+    }
+
+    #[test]
+    fn test_truncate_extend() {
+        const WIDE: Uint<256, 4> = Uint::<64, 1>::MAX.extend();
+        assert_eq!(WIDE, Uint::from(u64::MAX));
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let low: Uint<64, 1> = value.truncate();
+                assert_eq!(low, Uint::wrapping_from(value));
+                let low: Uint<7, 1> = value.truncate();
+                assert_eq!(low.to::<u64>(), value.as_limbs().first().map_or(0, |l| l & 0x7f));
+                let wide: Uint<4160, 65> = value.extend();
+                assert_eq!(wide, Uint::from(value));
+                assert_eq!(wide.truncate::<BITS, LIMBS>(), value);
+            });
+        });
+    }
+
     #[test]
     #[cfg(feature = "std")]
     fn test_f64() {
@@ -752,4 +1175,95 @@ mod test {
             Ok(Uint::from_limbs([124]))
         );
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[allow(clippy::cast_precision_loss, clippy::float_cmp)]
+    fn test_to_f64_exact() {
+        type U256 = Uint<256, 4>;
+        type U1100 = Uint<1100, 18>;
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(mantissa: u64, shift in 0_usize..1024)| {
+                // Values with at most 53 significant bits below 2^1024.
+                let mantissa = mantissa >> 11;
+                let value = U::try_from(mantissa).ok().filter(|v| v.bit_len() + shift <= BITS);
+                if let Some(value) = value.map(|v| v << shift) {
+                    if value.bit_len() <= 1024 {
+                        let expected = mantissa as f64 * (shift as f64).exp2();
+                        assert_eq!(value.to_f64_exact(), Some(expected));
+                    }
+                }
+            });
+            proptest!(|(value: U)| {
+                if let Some(float) = value.to_f64_exact() {
+                    assert_eq!(U::from_f64_with_mode(float, RoundMode::Trunc), Ok(value));
+                }
+            });
+        });
+        assert_eq!(U256::ZERO.to_f64_exact(), Some(0.0));
+        let pow53 = U256::from(1) << 53_usize;
+        assert_eq!(pow53.to_f64_exact(), Some(2.0_f64.powi(53)));
+        assert_eq!(
+            (pow53 - U256::from(1)).to_f64_exact(),
+            Some(2.0_f64.powi(53) - 1.0)
+        );
+        assert_eq!((pow53 + U256::from(1)).to_f64_exact(), None);
+        assert_eq!(U256::MAX.to_f64_exact(), None);
+        assert_eq!(U1100::MAX.to_f64_exact(), None);
+        assert_eq!(
+            (U1100::from(1) << 1023_usize).to_f64_exact(),
+            Some(2.0_f64.powi(1023))
+        );
+        assert_eq!((U1100::from(1) << 1024_usize).to_f64_exact(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_f64_with_mode() {
+        type U = Uint<64, 1>;
+        let modes = [
+            RoundMode::Floor,
+            RoundMode::Ceil,
+            RoundMode::Trunc,
+            RoundMode::NearestEven,
+        ];
+        let cases: [(f64, [u64; 4]); 6] = [
+            (2.4, [2, 3, 2, 2]),
+            (2.5, [2, 3, 2, 2]),
+            (2.6, [2, 3, 2, 3]),
+            (3.5, [3, 4, 3, 4]),
+            (0.0, [0, 0, 0, 0]),
+            (-0.4, [u64::MAX, 0, 0, 0]),
+        ];
+        for (value, expected) in cases {
+            for (mode, expected) in modes.into_iter().zip(expected) {
+                let result = U::from_f64_with_mode(value, mode);
+                if expected == u64::MAX {
+                    assert_eq!(result, Err(ToUintError::ValueNegative(64, U::MAX)));
+                } else {
+                    assert_eq!(result, Ok(U::from(expected)), "{value} {mode:?}");
+                }
+            }
+        }
+        for (mode, magnitude) in modes.into_iter().zip([3_u64, 2, 2, 2]) {
+            assert_eq!(
+                U::from_f64_with_mode(-2.5, mode),
+                Err(ToUintError::ValueNegative(
+                    64,
+                    U::from(magnitude).wrapping_neg()
+                ))
+            );
+            assert_eq!(
+                U::from_f64_with_mode(f64::NAN, mode),
+                Err(ToUintError::NotANumber(64))
+            );
+            // Odd integers in [2^52, 2^53) are exact.
+            assert_eq!(
+                U::from_f64_with_mode(4503599627370497.0, mode),
+                Ok(U::from(4503599627370497_u64))
+            );
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{algorithms, Uint};
+use crate::{algorithms, Int, Uint};
 
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Compute the greatest common divisor of two [`Uint`]s.
@@ -17,6 +17,30 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         algorithms::gcd(self, other)
     }
 
+    /// Returns `true` if `self` and `other` have no common factor other than
+    /// one, i.e. $\gcd(\mathtt{self}, \mathtt{other}) = 1$.
+    ///
+    /// This is the precondition for [`Uint::inv_mod`] to succeed and for the
+    /// Chinese remainder theorem to apply.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint! {
+    /// assert!(8_U128.is_coprime(15_U128));
+    /// assert!(!6_U128.is_coprime(15_U128));
+    /// assert!(1_U128.is_coprime(0_U128));
+    /// assert!(!0_U128.is_coprime(0_U128));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_coprime(self, other: Self) -> bool {
+        // The only value with bit length one is one.
+        self.gcd(other).bit_len() == 1
+    }
+
     /// Compute the least common multiple of two [`Uint`]s or [`None`] if the
     /// result would be too large.
     #[inline]
@@ -30,22 +54,39 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     ///
     /// **Warning.** This is API is unstable and may change in a minor release.
     ///
-    /// Returns $(\mathtt{gcd}, \mathtt{x}, \mathtt{y}, \mathtt{sign})$ such
-    /// that
+    /// Returns $(\mathtt{gcd}, \mathtt{x}, \mathtt{y})$ with signed
+    /// coefficients such that
     ///
     /// $$
-    /// \gcd(\mathtt{self}, \mathtt{other}) = \mathtt{gcd} = \begin{cases}
-    ///     \mathtt{self} · \mathtt{x} - \mathtt{other} . \mathtt{y} &
-    /// \mathtt{sign} \\\\     \mathtt{other} · \mathtt{y} - \mathtt{self} ·
-    /// \mathtt{x} & ¬\mathtt{sign} \end{cases}
+    /// \gcd(\mathtt{self}, \mathtt{other}) = \mathtt{gcd} = \mathtt{self} ·
+    /// \mathtt{x} + \mathtt{other} · \mathtt{y}
     /// $$
     ///
-    /// Note that the intermediate products may overflow, even though the result
-    /// after subtraction will fit in the bit size of the [`Uint`].
+    /// The coefficients are the minimal ones produced by the extended Euclidean
+    /// algorithm and fit in an [`Int`] for `BITS >= 2`. For `BITS == 1` a
+    /// coefficient of one wraps to minus one. The identity always holds
+    /// modulo $2^{\mathtt{BITS}}$, even though the intermediate products may
+    /// overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Int, Uint, aliases::*};
+    /// let (g, x, y) = U64::from(240).gcd_extended(U64::from(46));
+    /// assert_eq!(g, U64::from(2));
+    /// assert_eq!(x, -Int::from(U64::from(9)));
+    /// assert_eq!(y, Int::from(U64::from(47)));
+    /// ```
     #[inline]
     #[must_use]
-    pub fn gcd_extended(self, other: Self) -> (Self, Self, Self, bool) {
-        algorithms::gcd_extended(self, other)
+    pub fn gcd_extended(self, other: Self) -> (Self, Int<BITS, LIMBS>, Int<BITS, LIMBS>) {
+        let (gcd, x, y, sign) = algorithms::gcd_extended(self, other);
+        let (x, y) = (Int::from(x), Int::from(y));
+        if sign {
+            (gcd, x, -y)
+        } else {
+            (gcd, -x, y)
+        }
     }
 }
 
@@ -83,14 +124,42 @@ mod tests {
                     }
                 }
 
-                let (ge, x, y, sign) = a.gcd_extended(b);
+                assert_eq!(a.is_coprime(b), BITS > 0 && g == U::from(1));
+                assert_eq!(b.is_coprime(a), a.is_coprime(b));
+
+                let (ge, x, y) = a.gcd_extended(b);
                 assert_eq!(ge, g);
-                if sign {
-                    assert_eq!(a * x - b * y, g);
-                } else {
-                    assert_eq!(b * y - a * x, g);
+                assert_eq!(a * x.into_uint() + b * y.into_uint(), g);
+                if BITS >= 2 && g != U::ZERO {
+                    // Exactly one coefficient is negative, unless one is zero.
+                    assert!(!(x.is_negative() && y.is_negative()));
+                    assert!(x.is_negative() || y.is_negative() || x == Int::ZERO || y == Int::ZERO);
+                    // Minimal coefficients, hence no sign wrap-around.
+                    assert!(x.unsigned_abs() <= (b / g).max(U::from(1)));
+                    assert!(y.unsigned_abs() <= (a / g).max(U::from(1)));
                 }
             });
         });
     }
+
+    #[test]
+    fn test_is_coprime_prime() {
+        fn test<const BITS: usize, const LIMBS: usize>(p: Uint<BITS, LIMBS>) {
+            assert!(!p.is_coprime(p));
+            assert!(!Uint::ZERO.is_coprime(p));
+            let config = Config {
+                cases: 50,
+                ..Default::default()
+            };
+            proptest!(config, |(a: Uint<BITS, LIMBS>, k: u8)| {
+                assert_eq!(a.is_coprime(p), a.inv_mod(p).is_some());
+                assert_eq!(a.is_coprime(p), a % p != Uint::ZERO);
+                assert_eq!(Uint::from(k).is_coprime(p), k != 0);
+            });
+        }
+        // Mersenne primes 2^61 - 1 and 2^127 - 1 and 2^255 - 19.
+        test(Uint::<64, 1>::from(u64::MAX >> 3));
+        test(Uint::<128, 2>::from(u128::MAX >> 1));
+        test(Uint::<256, 4>::MAX >> 1_usize ^ Uint::from(18));
+    }
 }
@@ -0,0 +1,228 @@
+use crate::Uint;
+use core::{cmp::Ordering, ops::Neg};
+
+/// A newtype wrapper around [`Uint`] that interprets the value as a
+/// two's-complement signed integer.
+///
+/// The most significant bit (bit `BITS - 1`) is the sign bit. The range of
+/// representable values is $[-2^{BITS - 1}, 2^{BITS - 1})$.
+///
+/// Only the operations that differ between signed and unsigned integers are
+/// provided here. Use [`Int::into_uint`] and [`From<Uint>`] to move between
+/// the two interpretations of the same bits.
+#[derive(Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "alloc", derive(Debug))]
+pub struct Int<const BITS: usize, const LIMBS: usize>(Uint<BITS, LIMBS>);
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for Int<BITS, LIMBS> {
+    #[inline]
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        Self(value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Int<BITS, LIMBS>> for Uint<BITS, LIMBS> {
+    #[inline]
+    fn from(value: Int<BITS, LIMBS>) -> Self {
+        value.0
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Int<BITS, LIMBS> {
+    /// The size of this integer type in 64-bit limbs.
+    pub const LIMBS: usize = Uint::<BITS, LIMBS>::LIMBS;
+
+    /// The size of this integer type in bits.
+    pub const BITS: usize = Uint::<BITS, LIMBS>::BITS;
+
+    /// The value zero.
+    pub const ZERO: Self = Self(Uint::<BITS, LIMBS>::ZERO);
+
+    /// The smallest value that can be represented by this integer type,
+    /// $-2^{BITS - 1}$.
+    pub const MIN: Self = {
+        let mut limbs = [0; LIMBS];
+        if BITS > 0 {
+            limbs[(BITS - 1) / 64] = 1 << ((BITS - 1) % 64);
+        }
+        Self(Uint::from_limbs(limbs))
+    };
+
+    /// The largest value that can be represented by this integer type,
+    /// $2^{BITS - 1} − 1$.
+    pub const MAX: Self = {
+        let mut limbs = Uint::<BITS, LIMBS>::MAX.into_limbs();
+        if BITS > 0 {
+            limbs[(BITS - 1) / 64] ^= 1 << ((BITS - 1) % 64);
+        }
+        Self(Uint::from_limbs(limbs))
+    };
+
+    /// Returns the underlying two's-complement bits as a [`Uint`].
+    #[must_use]
+    #[inline(always)]
+    pub const fn into_uint(self) -> Uint<BITS, LIMBS> {
+        self.0
+    }
+
+    /// Returns a reference to the underlying [`Uint`].
+    #[must_use]
+    #[inline(always)]
+    pub const fn as_uint(&self) -> &Uint<BITS, LIMBS> {
+        &self.0
+    }
+
+    /// Returns `true` if the value is strictly less than zero.
+    #[must_use]
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        BITS > 0 && self.0.bit(BITS - 1)
+    }
+
+    /// Computes $\mod{-\mathtt{self}}_{2^{BITS}}$.
+    ///
+    /// The only case where this wraps is [`Int::MIN`], which is its own
+    /// negation.
+    #[must_use]
+    #[inline]
+    pub const fn wrapping_neg(self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+
+    /// Returns the absolute value as an unsigned [`Uint`].
+    ///
+    /// This never overflows, the magnitude of [`Int::MIN`] is $2^{BITS - 1}$.
+    #[must_use]
+    #[inline]
+    pub const fn unsigned_abs(self) -> Uint<BITS, LIMBS> {
+        if self.is_negative() {
+            self.0.wrapping_neg()
+        } else {
+            self.0
+        }
+    }
+
+    /// Returns the absolute value.
+    ///
+    /// Like [`i64::wrapping_abs`], the absolute value of [`Int::MIN`] can not
+    /// be represented and [`Int::MIN`] is returned instead.
+    #[must_use]
+    #[inline]
+    pub const fn abs(self) -> Self {
+        Self(self.unsigned_abs())
+    }
+
+    /// Computes the signed quotient and remainder of `self` divided by `rhs`.
+    ///
+    /// The quotient is rounded towards zero and the remainder has the same
+    /// sign as `self`, matching Rust's `/` and `%` on primitive signed
+    /// integers (and the EVM `SDIV` and `SMOD` opcodes). Dividing
+    /// [`Int::MIN`] by $-1$ wraps around to [`Int::MIN`] with remainder zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    #[must_use]
+    #[inline]
+    #[track_caller]
+    pub fn signed_div_rem(self, rhs: Self) -> (Self, Self) {
+        let (quotient, remainder) = self.unsigned_abs().div_rem(rhs.unsigned_abs());
+        let quotient = if self.is_negative() == rhs.is_negative() {
+            Self(quotient)
+        } else {
+            Self(quotient).wrapping_neg()
+        };
+        let remainder = if self.is_negative() {
+            Self(remainder).wrapping_neg()
+        } else {
+            Self(remainder)
+        };
+        (quotient, remainder)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Neg for Int<BITS, LIMBS> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        self.wrapping_neg()
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> PartialOrd for Int<BITS, LIMBS> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Ord for Int<BITS, LIMBS> {
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        // Two's complement values with equal sign compare like their bits.
+        match (self.is_negative(), rhs.is_negative()) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            _ => self.0.cmp(&rhs.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U64, const_for, nlimbs};
+    use proptest::proptest;
+
+    type I64 = Int<64, 1>;
+
+    fn from_i64(value: i64) -> I64 {
+        Int::from(U64::from(value as u64))
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(I64::MIN, from_i64(i64::MIN));
+        assert_eq!(I64::MAX, from_i64(i64::MAX));
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type I = Int<BITS, LIMBS>;
+            assert!(I::MIN <= I::ZERO);
+            assert!(I::ZERO <= I::MAX);
+            assert_eq!(I::MIN.wrapping_neg(), I::MIN);
+            assert_eq!(I::MIN.into_uint() ^ I::MAX.into_uint(), Uint::MAX);
+        });
+    }
+
+    #[test]
+    fn test_i64() {
+        proptest!(|(a: i64, b: i64)| {
+            let (x, y) = (from_i64(a), from_i64(b));
+            assert_eq!(x.is_negative(), a < 0);
+            assert_eq!(x.cmp(&y), a.cmp(&b));
+            assert_eq!(x.wrapping_neg(), from_i64(a.wrapping_neg()));
+            assert_eq!(x.abs(), from_i64(a.wrapping_abs()));
+            assert_eq!(x.unsigned_abs(), U64::from(a.unsigned_abs()));
+            if b != 0 {
+                let (q, r) = x.signed_div_rem(y);
+                assert_eq!(q, from_i64(a.wrapping_div(b)));
+                assert_eq!(r, from_i64(a.wrapping_rem(b)));
+            }
+        });
+    }
+
+    #[test]
+    fn test_div_rem_edge_cases() {
+        let minus_one = from_i64(-1);
+        assert_eq!(I64::MIN.signed_div_rem(minus_one), (I64::MIN, I64::ZERO));
+        assert_eq!(
+            from_i64(-7).signed_div_rem(from_i64(2)),
+            (from_i64(-3), from_i64(-1))
+        );
+        assert_eq!(
+            from_i64(7).signed_div_rem(from_i64(-2)),
+            (from_i64(-3), from_i64(1))
+        );
+    }
+}
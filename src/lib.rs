@@ -56,10 +56,13 @@ mod div;
 mod fmt;
 mod from;
 mod gcd;
+mod int;
 mod log;
 mod modular;
 mod mul;
+mod non_zero;
 mod pow;
+mod reciprocal;
 mod root;
 mod special;
 mod string;
@@ -70,14 +73,28 @@ pub mod support;
 #[doc(inline)]
 pub use bit_arr::Bits;
 
+#[doc(inline)]
+pub use int::Int;
+
+#[doc(inline)]
+pub use non_zero::NonZeroUint;
+
+#[doc(inline)]
+pub use reciprocal::Reciprocal;
+
 #[doc(inline)]
 pub use self::{
-    base_convert::BaseConvertError,
+    base_convert::{BaseConvertError, BoundedBaseConvertError},
+    bits::ShiftOverflow,
     bytes::nbytes,
-    from::{FromUintError, ToFieldError, ToUintError, UintTryFrom, UintTryTo},
-    string::ParseError,
+    from::{FromLimbsError, FromUintError, ToFieldError, ToUintError, UintTryFrom, UintTryTo},
+    string::{FromStrRadixError, ParseError},
 };
 
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use from::RoundMode;
+
 // For documentation purposes we expose the macro directly, otherwise it is
 // wrapped in ./macros.rs.
 #[cfg(doc)]
@@ -177,6 +194,12 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Synonym for [`Self::ZERO`].
     pub const MIN: Self = Self::ZERO;
 
+    /// The value returned by [`Default::default`], i.e. [`Self::ZERO`].
+    ///
+    /// Trait methods can not be called in `const` contexts, so use this
+    /// instead where a `const` default is needed.
+    pub const DEFAULT: Self = Self::ZERO;
+
     /// The largest value that can be represented by this integer type,
     /// $2^{\mathtt{BITS}} − 1$.
     pub const MAX: Self = {
@@ -199,13 +222,61 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// # Safety
     ///
     /// This function is unsafe because it allows setting a bit outside the bit
-    /// size if the bit-size is not limb-aligned.
+    /// size if the bit-size is not limb-aligned. Use [`Self::masked`] to clear
+    /// those bits again.
     #[inline(always)]
     #[must_use]
     pub unsafe fn as_limbs_mut(&mut self) -> &mut [u64; LIMBS] {
         &mut self.limbs
     }
 
+    /// Clears the bits of the last limb beyond `BITS`.
+    ///
+    /// This restores the invariant after the limbs were modified through
+    /// [`Self::as_limbs_mut`]. It is a no-op on any other value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint};
+    /// # uint!{
+    /// let mut value = 3_U65;
+    /// unsafe {
+    ///     value.as_limbs_mut()[1] = u64::MAX;
+    /// }
+    /// assert_eq!(value.masked(), 0x1_0000000000000003_U65);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn masked(mut self) -> Self {
+        if BITS > 0 {
+            self.limbs[LIMBS - 1] &= Self::MASK;
+        }
+        self
+    }
+
+    /// Returns `true` if no bits beyond `BITS` are set.
+    ///
+    /// This is always the case unless the limbs were modified through
+    /// [`Self::as_limbs_mut`].
+    #[inline]
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        BITS == 0 || self.limbs[LIMBS - 1] <= Self::MASK
+    }
+
+    /// Asserts [`Self::is_valid`] in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if bits beyond `BITS` are set.
+    #[inline]
+    #[track_caller]
+    pub fn debug_assert_valid(&self) {
+        debug_assert!(self.is_valid(), "Uint has bits set beyond BITS");
+    }
+
     /// Convert to a array of limbs.
     ///
     /// Limbs are least significant first.
@@ -221,7 +292,8 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     ///
     /// Panics it `LIMBS` is not equal to `nlimbs(BITS)`.
     ///
-    /// Panics if the value is too large for the bit-size of the Uint.
+    /// Panics if the value is too large for the bit-size of the Uint. See
+    /// [`Self::checked_from_limbs`] for a non-panicking version.
     #[inline(always)]
     #[must_use]
     #[track_caller]
@@ -236,6 +308,105 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         Self { limbs }
     }
 
+    /// Construct a new integer from little-endian a array of limbs, or `None`
+    /// if the value is too large for the [`Uint`].
+    ///
+    /// This is the non-panicking version of [`Self::from_limbs`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint};
+    /// # uint!{
+    /// assert_eq!(
+    ///     Uint::<65, 2>::checked_from_limbs([3, 1]),
+    ///     Some(0x1_0000000000000003_U65)
+    /// );
+    /// assert_eq!(Uint::<65, 2>::checked_from_limbs([3, 2]), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn checked_from_limbs(limbs: [u64; LIMBS]) -> Option<Self> {
+        if BITS > 0 && Self::MASK != u64::MAX && limbs[Self::LIMBS - 1] > Self::MASK {
+            return None;
+        }
+        Some(Self { limbs })
+    }
+
+    /// Construct a new integer from 64-bit words, least significant first.
+    ///
+    /// Unlike [`Self::from_limbs`] the slice can have any length. Missing high
+    /// words are zero and excess high words must be zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is too large for the bit-size of the Uint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint};
+    /// # uint!{
+    /// const VALUE: Uint<128, 2> = Uint::from_words_le(&[3, 1]);
+    /// assert_eq!(VALUE, 0x1_0000000000000003_U128);
+    /// assert_eq!(Uint::<128, 2>::from_words_le(&[3]), 3_U128);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn from_words_le(words: &[u64]) -> Self {
+        let mut limbs = [0; LIMBS];
+        let mut i = 0;
+        while i < words.len() {
+            if i < LIMBS {
+                limbs[i] = words[i];
+            } else {
+                assert!(words[i] == 0, "Value too large for this Uint");
+            }
+            i += 1;
+        }
+        Self::from_limbs(limbs)
+    }
+
+    /// Construct a new integer from 64-bit words, most significant first.
+    ///
+    /// Unlike [`Self::from_limbs`] the slice can have any length. Missing high
+    /// words are zero and excess high words must be zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is too large for the bit-size of the Uint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint};
+    /// # uint!{
+    /// const VALUE: Uint<128, 2> = Uint::from_words_be(&[1, 3]);
+    /// assert_eq!(VALUE, 0x1_0000000000000003_U128);
+    /// assert_eq!(Uint::<128, 2>::from_words_be(&[3]), 3_U128);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn from_words_be(words: &[u64]) -> Self {
+        let mut limbs = [0; LIMBS];
+        let mut i = 0;
+        while i < words.len() {
+            let word = words[words.len() - 1 - i];
+            if i < LIMBS {
+                limbs[i] = word;
+            } else {
+                assert!(word == 0, "Value too large for this Uint");
+            }
+            i += 1;
+        }
+        Self::from_limbs(limbs)
+    }
+
     /// Construct a new integer from little-endian a slice of limbs.
     ///
     /// # Panics
@@ -308,7 +479,54 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
 impl<const BITS: usize, const LIMBS: usize> Default for Uint<BITS, LIMBS> {
     #[inline]
     fn default() -> Self {
-        Self::ZERO
+        Self::DEFAULT
+    }
+}
+
+/// Iterates over the limbs, least significant first.
+impl<const BITS: usize, const LIMBS: usize> IntoIterator for Uint<BITS, LIMBS> {
+    type Item = u64;
+    type IntoIter = core::array::IntoIter<u64, LIMBS>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.limbs.into_iter()
+    }
+}
+
+/// Iterates over the limbs, least significant first.
+// Use `as_limbs().iter()` directly, an inherent `iter` would be ambiguous
+// between limbs and bits.
+#[allow(clippy::into_iter_without_iter)]
+impl<'a, const BITS: usize, const LIMBS: usize> IntoIterator for &'a Uint<BITS, LIMBS> {
+    type Item = &'a u64;
+    type IntoIter = core::slice::Iter<'a, u64>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.limbs.iter()
+    }
+}
+
+/// Collects limbs, least significant first.
+///
+/// Missing limbs are zero and bits of the last limb beyond `BITS` are masked
+/// off, see [`Uint::wrapping_from_limbs_slice`].
+///
+/// # Panics
+///
+/// Panics if the iterator yields more than `LIMBS` limbs.
+impl<const BITS: usize, const LIMBS: usize> FromIterator<u64> for Uint<BITS, LIMBS> {
+    #[inline]
+    #[track_caller]
+    fn from_iter<I: IntoIterator<Item = u64>>(iter: I) -> Self {
+        let mut limbs = [0; LIMBS];
+        let mut iter = iter.into_iter();
+        for (limb, value) in limbs.iter_mut().zip(iter.by_ref()) {
+            *limb = value;
+        }
+        assert!(iter.next().is_none(), "Too many limbs for this Uint");
+        Self { limbs }.masked()
     }
 }
 
@@ -344,6 +562,148 @@ pub mod __private {
 #[cfg(test)]
 mod test {
     use super::*;
+    use proptest::proptest;
+
+    #[test]
+    fn test_limb_iter() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                assert_eq!(value.into_iter().collect::<U>(), value);
+                assert!(value.into_iter().eq(value.as_limbs().iter().copied()));
+                assert_eq!((&value).into_iter().copied().collect::<U>(), value);
+            });
+        });
+        assert_eq!(
+            [1, 2].into_iter().collect::<Uint<256, 4>>(),
+            Uint::from_limbs([1, 2, 0, 0])
+        );
+        assert_eq!(
+            [u64::MAX, u64::MAX].into_iter().collect::<Uint<65, 2>>(),
+            Uint::MAX
+        );
+    }
+
+    #[test]
+    fn test_const_assert_limbs() {
+        crate::const_assert_limbs!(0, 0);
+        crate::const_assert_limbs!(64, 1);
+        crate::const_assert_limbs!(65, 2);
+        crate::const_assert_limbs!(4096, nlimbs(4096));
+    }
+
+    #[test]
+    fn test_from_words() {
+        const LE: Uint<65, 2> = Uint::from_words_le(&[3, 1, 0]);
+        const BE: Uint<65, 2> = Uint::from_words_be(&[0, 1, 3]);
+        const _: () = {
+            assert!(LE.as_limbs()[0] == 3 && LE.as_limbs()[1] == 1);
+            assert!(BE.as_limbs()[0] == 3 && BE.as_limbs()[1] == 1);
+            assert!(Uint::<0, 0>::from_words_le(&[0, 0]).as_limbs().is_empty());
+            let empty = Uint::<128, 2>::from_words_be(&[]);
+            assert!(empty.as_limbs()[0] == 0 && empty.as_limbs()[1] == 0);
+        };
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let mut words = value.as_limbs().to_vec();
+                assert_eq!(U::from_words_le(&words), value);
+                words.reverse();
+                assert_eq!(U::from_words_be(&words), value);
+            });
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Value too large for this Uint")]
+    fn test_from_words_le_too_large() {
+        let _ = Uint::<64, 1>::from_words_le(&[1, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value too large for this Uint")]
+    fn test_from_words_be_too_large() {
+        let _ = Uint::<65, 2>::from_words_be(&[2, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Too many limbs for this Uint")]
+    fn test_from_iter_too_many() {
+        let _: Uint<64, 1> = [1, 0].into_iter().collect();
+    }
+
+    #[test]
+    fn test_checked_from_limbs() {
+        const IN_RANGE: Option<Uint<100, 2>> = Uint::checked_from_limbs([u64::MAX, u64::MAX >> 28]);
+        const OVER_MASK: Option<Uint<100, 2>> = Uint::checked_from_limbs([0, 1 << 36]);
+        const FULL: Option<Uint<128, 2>> = Uint::checked_from_limbs([u64::MAX; 2]);
+        const EMPTY: Option<Uint<0, 0>> = Uint::checked_from_limbs([]);
+        assert_eq!(IN_RANGE, Some(Uint::MAX));
+        assert_eq!(OVER_MASK, None);
+        assert_eq!(FULL, Some(Uint::MAX));
+        assert_eq!(EMPTY, Some(Uint::ZERO));
+        assert_eq!(Uint::<1, 1>::checked_from_limbs([2]), None);
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, high in 0_u64..)| {
+                assert_eq!(U::checked_from_limbs(value.into_limbs()), Some(value));
+                let padding = high & !U::MASK;
+                let mut limbs = value.into_limbs();
+                limbs[LIMBS - 1] |= padding;
+                assert_eq!(U::checked_from_limbs(limbs), (padding == 0).then_some(value));
+            });
+        });
+    }
+
+    #[test]
+    fn test_masked() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, high: u64)| {
+                assert_eq!(value.masked(), value);
+                let mut dirty = value;
+                unsafe {
+                    dirty.as_limbs_mut()[LIMBS - 1] |= high;
+                }
+                let masked = dirty.masked();
+                assert_eq!(masked.as_limbs()[LIMBS - 1] & !U::MASK, 0);
+                assert_eq!(masked, U::wrapping_from_limbs_slice(dirty.as_limbs()));
+            });
+        });
+    }
+
+    #[test]
+    fn test_is_valid() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                assert!(value.is_valid());
+                value.debug_assert_valid();
+            });
+        });
+        let mut value = Uint::<65, 2>::MAX;
+        unsafe {
+            value.as_limbs_mut()[1] = 2;
+        }
+        assert!(!value.is_valid());
+        assert!(value.masked().is_valid());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Uint has bits set beyond BITS")]
+    fn test_debug_assert_valid() {
+        let mut value = Uint::<1, 1>::ZERO;
+        unsafe {
+            value.as_limbs_mut()[0] = 2;
+        }
+        value.debug_assert_valid();
+    }
 
     #[test]
     fn test_mask() {
@@ -366,6 +726,19 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_default() {
+        const DEFAULT: Uint<256, 4> = Uint::DEFAULT;
+        const EMPTY: [Uint<65, 2>; 3] = [Uint::DEFAULT; 3];
+        assert_eq!(DEFAULT, Uint::ZERO);
+        assert_eq!(EMPTY, [Uint::ZERO; 3]);
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            assert_eq!(U::DEFAULT, U::default());
+        });
+    }
+
     #[test]
     fn test_constants() {
         const_for!(BITS in SIZES {
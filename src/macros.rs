@@ -8,6 +8,34 @@ macro_rules! uint {
     }
 }
 
+/// Compile time check that `LIMBS` is `nlimbs(BITS)` for a [`Uint`] type.
+///
+/// Expands to a `const` item, so a mismatch is a compile error rather than a
+/// panic on first use. This is intended for type aliases with concrete
+/// widths. In generic code referring to
+/// [`Uint::<BITS, LIMBS>::LIMBS`](crate::Uint::LIMBS) performs the same check
+/// at monomorphization.
+///
+/// # Examples
+///
+/// ```
+/// # use ruint::{const_assert_limbs, Uint};
+/// type U100 = Uint<100, 2>;
+/// const_assert_limbs!(100, 2);
+/// ```
+///
+/// ```compile_fail
+/// # use ruint::{const_assert_limbs, Uint};
+/// type U100 = Uint<100, 3>;
+/// const_assert_limbs!(100, 3);
+/// ```
+#[macro_export]
+macro_rules! const_assert_limbs {
+    ($bits:expr, $limbs:expr $(,)?) => {
+        const _: usize = $crate::Uint::<{ $bits }, { $limbs }>::LIMBS;
+    };
+}
+
 macro_rules! impl_bin_op {
     ($trait:ident, $fn:ident, $trait_assign:ident, $fn_assign:ident, $fdel:ident) => {
         impl<const BITS: usize, const LIMBS: usize> $trait_assign<Uint<BITS, LIMBS>>
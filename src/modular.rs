@@ -1,6 +1,6 @@
 use crate::{algorithms, Uint};
 
-// FEATURE: sub_mod, neg_mod, inv_mod, div_mod, root_mod
+// FEATURE: neg_mod, div_mod, root_mod
 // See <https://en.wikipedia.org/wiki/Cipolla's_algorithm>
 // FEATURE: mul_mod_redc
 // and maybe barrett
@@ -20,12 +20,44 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         if modulus.is_zero() {
             return Self::ZERO;
         }
+        if modulus.is_power_of_two() {
+            return self.reduce_pow2(modulus.trailing_zeros());
+        }
         if self >= modulus {
             self %= modulus;
         }
         self
     }
 
+    /// Compute $\mod{\mathtt{self}}_{2^k}$ by keeping the low `k` bits.
+    ///
+    /// Returns `self` if `k >= BITS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(0x1234_U64.reduce_pow2(8), 0x34_U64);
+    /// assert_eq!(0x1234_U64.reduce_pow2(0), 0_U64);
+    /// assert_eq!(0x1234_U64.reduce_pow2(64), 0x1234_U64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn reduce_pow2(mut self, k: usize) -> Self {
+        if k >= BITS {
+            return self;
+        }
+        self.limbs[k / 64] &= (1 << (k % 64)) - 1;
+        let mut i = k / 64 + 1;
+        while i < LIMBS {
+            self.limbs[i] = 0;
+            i += 1;
+        }
+        self
+    }
+
     /// Compute $\mod{\mathtt{self} + \mathtt{rhs}}_{\mathtt{modulus}}$.
     ///
     /// Returns zero if the modulus is zero.
@@ -44,6 +76,74 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         result
     }
 
+    /// Compute $\mod{\mathtt{self} + \mathtt{rhs}}_{\mathtt{modulus}}$, or
+    /// `None` if the modulus is zero.
+    ///
+    /// Unlike [`Self::add_mod`] this distinguishes a zero result from an
+    /// invalid modulus.
+    #[inline]
+    #[must_use]
+    pub fn checked_add_mod(self, rhs: Self, modulus: Self) -> Option<Self> {
+        if modulus.is_zero() {
+            return None;
+        }
+        Some(self.add_mod(rhs, modulus))
+    }
+
+    /// Compute $\mod{\mathtt{self} - \mathtt{rhs}}_{\mathtt{modulus}}$.
+    ///
+    /// Returns zero if the modulus is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(5_U64.sub_mod(3_U64, 7_U64), 2_U64);
+    /// assert_eq!(3_U64.sub_mod(5_U64, 7_U64), 5_U64);
+    /// assert_eq!(3_U64.sub_mod(5_U64, 0_U64), 0_U64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sub_mod(self, rhs: Self, modulus: Self) -> Self {
+        // Reduce inputs
+        let lhs = self.reduce_mod(modulus);
+        let rhs = rhs.reduce_mod(modulus);
+
+        // Compute the difference and conditionally add modulus once.
+        let (result, borrow) = lhs.overflowing_sub(rhs);
+        if borrow {
+            result.wrapping_add(modulus)
+        } else {
+            result
+        }
+    }
+
+    /// Compute $\mod{\mathtt{self} - \mathtt{rhs}}_{\mathtt{modulus}}$, or
+    /// `None` if the modulus is zero.
+    ///
+    /// Unlike [`Self::sub_mod`] this distinguishes a zero result from an
+    /// invalid modulus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(3_U64.checked_sub_mod(5_U64, 7_U64), Some(5_U64));
+    /// assert_eq!(3_U64.checked_sub_mod(5_U64, 0_U64), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_sub_mod(self, rhs: Self, modulus: Self) -> Option<Self> {
+        if modulus.is_zero() {
+            return None;
+        }
+        Some(self.sub_mod(rhs, modulus))
+    }
+
     /// Compute $\mod{\mathtt{self} ⋅ \mathtt{rhs}}_{\mathtt{modulus}}$.
     ///
     /// Returns zero if the modulus is zero.
@@ -78,17 +178,100 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         modulus
     }
 
-    /// Compute $\mod{\mathtt{self}^{\mathtt{rhs}}}_{\mathtt{modulus}}$.
+    /// Compute $\mod{\mathtt{self} ⋅ \mathtt{rhs}}_{\mathtt{modulus}}$, or
+    /// `None` if the modulus is zero.
+    ///
+    /// Unlike [`Self::mul_mod`] this distinguishes a zero result from an
+    /// invalid modulus.
+    #[inline]
+    #[must_use]
+    pub fn checked_mul_mod(self, rhs: Self, modulus: Self) -> Option<Self> {
+        if modulus.is_zero() {
+            return None;
+        }
+        Some(self.mul_mod(rhs, modulus))
+    }
+
+    /// Compute $\mod{\sum_i \mathtt{a}_i ⋅ \mathtt{b}_i}_{\mathtt{modulus}}$.
     ///
     /// Returns zero if the modulus is zero.
+    ///
+    /// The products are accumulated in full width and reduced once at the
+    /// end, which is much faster than summing the terms with
+    /// [`Self::mul_mod`] and [`Self::add_mod`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// let a = [2_U64, 3_U64, 4_U64];
+    /// let b = [5_U64, 6_U64, 7_U64];
+    /// assert_eq!(U64::dot_mod(&a, &b, 10_U64), 6_U64);
+    /// assert_eq!(U64::dot_mod(&a, &b, 0_U64), 0_U64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn dot_mod(a: &[Self], b: &[Self], mut modulus: Self) -> Self {
+        assert_eq!(a.len(), b.len(), "Slices have different lengths");
+        if modulus.is_zero() {
+            return Self::ZERO;
+        }
+
+        // Allocate `2 * LIMBS + 1` limbs for the sum. Each product fits in
+        // `2 * LIMBS` limbs and the extra limb can absorb the carries of more
+        // terms than a slice can hold. See `mul_mod` for the array casting.
+        let mut sum = [[0u64; 3]; LIMBS];
+        let sum_len = 2 * LIMBS + 1;
+        debug_assert!(3 * LIMBS >= sum_len);
+        // SAFETY: `[[u64; 3]; LIMBS] == [u64; 3 * LIMBS] >= [u64; 2 * LIMBS + 1]`.
+        let sum =
+            unsafe { core::slice::from_raw_parts_mut(sum.as_mut_ptr().cast::<u64>(), sum_len) };
+
+        for (a, b) in a.iter().zip(b) {
+            let overflow = algorithms::addmul(sum, a.as_limbs(), b.as_limbs());
+            debug_assert!(!overflow);
+        }
+
+        // This stores the remainder in the divisor, `modulus`.
+        algorithms::div(sum, &mut modulus.limbs);
+
+        modulus
+    }
+
+    /// Compute $\mod{\mathtt{self}^{\mathtt{rhs}}}_{\mathtt{modulus}}$.
+    ///
+    /// Returns zero if the modulus is zero. For a modulus of one every value
+    /// is congruent to zero, so the result is zero for all exponents, including
+    /// zero. For larger moduli $\mathtt{self}^0 = 1$, also when `self` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(3_U64.pow_mod(0_U64, 1_U64), 0_U64);
+    /// assert_eq!(0_U64.pow_mod(0_U64, 7_U64), 1_U64);
+    /// assert_eq!(10_U64.pow_mod(2_U64, 7_U64), 2_U64);
+    /// # }
+    /// ```
     #[inline]
     #[must_use]
     pub fn pow_mod(mut self, mut exp: Self, modulus: Self) -> Self {
-        if modulus.is_zero() || modulus <= Self::from(1) {
-            // Also covers Self::BITS == 0
+        // The zero check comes first as `Self::from(1)` panics for `BITS == 0`.
+        if modulus.is_zero() || modulus == Self::from(1) {
             return Self::ZERO;
         }
 
+        // Reduce the base so the squarings below operate on reduced values.
+        self = self.reduce_mod(modulus);
+
         // Exponentiation by squaring
         let mut result = Self::from(1);
         while exp > Self::ZERO {
@@ -104,6 +287,32 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         result
     }
 
+    /// Compute $\mod{\mathtt{self}^{\mathtt{rhs}}}_{\mathtt{modulus}}$, or
+    /// `None` if the modulus is zero.
+    ///
+    /// Unlike [`Self::pow_mod`] this distinguishes a zero result from an
+    /// invalid modulus. A modulus of one is valid and always results in zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(3_U64.checked_pow_mod(2_U64, 0_U64), None);
+    /// assert_eq!(3_U64.checked_pow_mod(2_U64, 1_U64), Some(0_U64));
+    /// assert_eq!(3_U64.checked_pow_mod(2_U64, 9_U64), Some(0_U64));
+    /// assert_eq!(10_U64.checked_pow_mod(2_U64, 7_U64), Some(2_U64));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_pow_mod(self, exp: Self, modulus: Self) -> Option<Self> {
+        if modulus.is_zero() {
+            return None;
+        }
+        Some(self.pow_mod(exp, modulus))
+    }
+
     /// Compute $\mod{\mathtt{self}^{-1}}_{\mathtt{modulus}}$.
     ///
     /// Returns `None` if the inverse does not exist.
@@ -220,6 +429,59 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_sub_mod() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U, m: U)| {
+                let diff = a.sub_mod(b, m);
+                assert_eq!(diff.add_mod(b, m), a.reduce_mod(m));
+                if m != U::ZERO {
+                    assert!(diff < m);
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_checked_mod_zero_modulus() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U, m: U)| {
+                assert_eq!(a.checked_add_mod(b, U::ZERO), None);
+                assert_eq!(a.checked_sub_mod(b, U::ZERO), None);
+                assert_eq!(a.checked_mul_mod(b, U::ZERO), None);
+                assert_eq!(a.sub_mod(b, U::ZERO), U::ZERO);
+                if m != U::ZERO {
+                    assert_eq!(a.checked_add_mod(b, m), Some(a.add_mod(b, m)));
+                    assert_eq!(a.checked_sub_mod(b, m), Some(a.sub_mod(b, m)));
+                    assert_eq!(a.checked_mul_mod(b, m), Some(a.mul_mod(b, m)));
+                }
+            });
+        });
+    }
+
+    #[test]
+    #[allow(clippy::absurd_extreme_comparisons)] // From macro.
+    fn test_reduce_pow2() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, k in 0..BITS + 70)| {
+                if k < BITS {
+                    let modulus = U::from(1) << k;
+                    assert_eq!(value.reduce_pow2(k), value % modulus);
+                    assert_eq!(value.reduce_mod(modulus), value % modulus);
+                    assert_eq!(value.reduce_pow2(k), value & (modulus - U::from(1)));
+                } else {
+                    assert_eq!(value.reduce_pow2(k), value);
+                }
+            });
+        });
+    }
+
     #[test]
     fn test_add_identity() {
         const_for!(BITS in NON_ZERO {
@@ -231,6 +493,43 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_add_mod_overflow() {
+        // `lhs + rhs` overflows `BITS` and the carry path must subtract the
+        // modulus exactly once. Checked against a wider reference computation.
+        const_for!(BITS in [63, 64, 65, 127, 128, 129, 256] {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            const BITS_WIDE: usize = BITS + 64;
+            const LIMBS_WIDE: usize = nlimbs(BITS_WIDE);
+            type W = Uint<BITS_WIDE, LIMBS_WIDE>;
+            let reference = |a: U, b: U, m: U| -> U {
+                let (a, b, m) = (W::from(a), W::from(b), W::from(m));
+                ((a % m + b % m) % m).to()
+            };
+            let one = U::from(1);
+            for (a, b, m) in [
+                (U::MAX, U::MAX, U::MAX),
+                (U::MAX - one, U::MAX - one, U::MAX),
+                (U::MAX - one, U::MAX - one, U::MAX - one),
+                (U::MAX - one, one, U::MAX),
+                (U::MAX - one, U::MAX - one, U::MAX - U::from(2)),
+                (U::MAX >> 1_usize, U::MAX >> 1_usize, (U::MAX >> 1_usize) + one),
+            ] {
+                assert_eq!(a.add_mod(b, m), reference(a, b, m));
+                assert_eq!(b.add_mod(a, m), reference(a, b, m));
+            }
+            proptest!(|(a: U, b: U, m: U)| {
+                // Force all operands into the top of the range.
+                let top = !(U::MAX >> 2_usize);
+                let (a, b, m) = (a | top, b | top, m | top);
+                let result = a.add_mod(b, m);
+                assert!(result < m);
+                assert_eq!(result, reference(a, b, m));
+            });
+        });
+    }
+
     #[test]
     fn test_mul_identity() {
         const_for!(BITS in NON_ZERO {
@@ -243,6 +542,35 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_dot_mod() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(terms: Vec<(U, U)>, m: U)| {
+                let (a, b): (Vec<U>, Vec<U>) = terms.into_iter().unzip();
+                let naive = a
+                    .iter()
+                    .zip(&b)
+                    .fold(U::ZERO, |acc, (&a, &b)| acc.add_mod(a.mul_mod(b, m), m));
+                assert_eq!(U::dot_mod(&a, &b, m), naive);
+            });
+            proptest!(|(m: U)| {
+                let a = [U::MAX; 16];
+                let square = U::MAX.mul_mod(U::MAX, m);
+                let naive = (0..16).fold(U::ZERO, |acc, _| acc.add_mod(square, m));
+                assert_eq!(U::dot_mod(&a, &a, m), naive);
+                assert_eq!(U::dot_mod(&[], &[], m), U::ZERO);
+            });
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Slices have different lengths")]
+    fn test_dot_mod_length() {
+        let _ = Uint::<64, 1>::dot_mod(&[Uint::ZERO], &[], Uint::from(7));
+    }
+
     #[test]
     fn test_pow_identity() {
         const_for!(BITS in NON_ZERO {
@@ -255,6 +583,73 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_pow_mod_edge_cases() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            let (zero, one) = (U::ZERO, U::from(1));
+            // Modulus one: everything is zero.
+            assert_eq!(zero.pow_mod(zero, one), zero);
+            assert_eq!(one.pow_mod(zero, one), zero);
+            assert_eq!(U::MAX.pow_mod(U::MAX, one), zero);
+            // Modulus zero.
+            assert_eq!(U::MAX.pow_mod(one, zero), zero);
+
+            // Too slow.
+            if LIMBS > 8 {
+                return;
+            }
+
+            let config = Config { cases: 10, ..Default::default() };
+            proptest!(config, |(a: U, e: U, m: U)| {
+                // Modulus one and exponent zero.
+                assert_eq!(a.pow_mod(e, one), zero);
+                if m > one {
+                    assert_eq!(a.pow_mod(zero, m), one);
+                    assert_eq!(zero.pow_mod(zero, m), one);
+                    // Unreduced bases agree with reduced ones.
+                    assert_eq!(a.pow_mod(e, m), a.reduce_mod(m).pow_mod(e, m));
+                    let (b, overflow) = a.reduce_mod(m).overflowing_add(m);
+                    if !overflow {
+                        assert_eq!(b.pow_mod(e, m), a.pow_mod(e, m));
+                    }
+                }
+            });
+        });
+        assert_eq!(U64::MAX.pow_mod(U64::from(2), U64::from(10)), U64::from(5));
+        assert_eq!(
+            U64::from(12).pow_mod(U64::from(3), U64::from(5)),
+            U64::from(3)
+        );
+    }
+
+    #[test]
+    fn test_checked_pow_mod() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(b: U, e in 0_u64..8, m: U)| {
+                let e = U::wrapping_from(e);
+                assert_eq!(b.checked_pow_mod(e, U::ZERO), None);
+                assert_eq!(b.checked_pow_mod(e, U::from(1)), Some(U::ZERO));
+                if !m.is_zero() {
+                    let mut expected = U::from(1).reduce_mod(m);
+                    let mut i = U::ZERO;
+                    while i < e {
+                        expected = expected.mul_mod(b, m);
+                        i += U::from(1);
+                    }
+                    assert_eq!(b.checked_pow_mod(e, m), Some(expected));
+                }
+            });
+        });
+        assert_eq!(
+            Uint::<0, 0>::ZERO.checked_pow_mod(Uint::ZERO, Uint::ZERO),
+            None
+        );
+    }
+
     #[test]
     fn test_pow_rules() {
         const_for!(BITS in NON_ZERO {
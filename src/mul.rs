@@ -3,6 +3,7 @@ use core::{
     iter::Product,
     num::Wrapping,
     ops::{Mul, MulAssign},
+    slice,
 };
 
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
@@ -69,6 +70,94 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         result
     }
 
+    /// Computes `self * self`, returning the wrapped square and whether
+    /// overflow occurred.
+    ///
+    /// This is equivalent to [`Self::overflowing_mul`] with `self` as both
+    /// arguments, but uses [`algorithms::sqr`] to compute each cross term
+    /// only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint};
+    /// # uint!{
+    /// assert_eq!(15_U8.overflowing_square(), (225_U8, false));
+    /// assert_eq!(16_U8.overflowing_square(), (0_U8, true));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overflowing_square(self) -> (Self, bool) {
+        let mut result = Self::ZERO;
+        let mut overflow = algorithms::sqr(&mut result.limbs, self.as_limbs());
+        if BITS > 0 {
+            overflow |= result.limbs[LIMBS - 1] > Self::MASK;
+            result.limbs[LIMBS - 1] &= Self::MASK;
+        }
+        (result, overflow)
+    }
+
+    /// Computes `self * self`, wrapping around at the boundary of the type.
+    #[inline(always)]
+    #[must_use]
+    pub fn wrapping_square(self) -> Self {
+        self.overflowing_square().0
+    }
+
+    /// Computes `self * rhs` for a single limb `rhs`, returning the wrapped
+    /// product and the carry-out.
+    ///
+    /// The carry-out is the part of the full product above `BITS`, i.e.
+    /// $\floor{\frac{\mathtt{self} ⋅ \mathtt{rhs}}{2^{\mathtt{BITS}}}}$, which
+    /// always fits in a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint};
+    /// # uint!{
+    /// assert_eq!(3_U8.mul_u64(5), (15_U8, 0));
+    /// assert_eq!(100_U8.mul_u64(1000), (160_U8, 390));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn mul_u64(self, rhs: u64) -> (Self, u64) {
+        if BITS == 0 {
+            return (Self::ZERO, 0);
+        }
+        let mut result = self;
+        let carry = algorithms::mul_nx1(&mut result.limbs, rhs);
+        let top = BITS % 64;
+        let high = if top == 0 {
+            carry
+        } else {
+            (carry << (64 - top)) | (result.limbs[LIMBS - 1] >> top)
+        };
+        result.limbs[LIMBS - 1] &= Self::MASK;
+        (result, high)
+    }
+
+    /// Computes `self * rhs` for a single limb `rhs`, wrapping around at the
+    /// boundary of the type.
+    #[inline(always)]
+    #[must_use]
+    pub fn wrapping_mul_u64(self, rhs: u64) -> Self {
+        self.mul_u64(rhs).0
+    }
+
+    /// Computes `self * rhs` for a single limb `rhs`, returning [`None`] if
+    /// overflow occurred.
+    #[inline(always)]
+    #[must_use]
+    pub fn checked_mul_u64(self, rhs: u64) -> Option<Self> {
+        match self.mul_u64(rhs) {
+            (value, 0) => Some(value),
+            _ => None,
+        }
+    }
+
     /// Computes the inverse modulo $2^{\mathtt{BITS}}$ of `self`, returning
     /// [`None`] if the inverse does not exist.
     #[inline]
@@ -147,6 +236,125 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
 
         result
     }
+
+    /// Computes the product `self * rhs` into a result of explicit width,
+    /// returning [`None`] if it does not fit.
+    ///
+    /// Unlike [`Self::widening_mul`] the result width `BITS_RES` is free, so a
+    /// `U256` times a `U64` can produce a `U320`, or any narrower width when
+    /// the caller knows the product is bounded. It is a compile time error if
+    /// `LIMBS_RES` does not match `BITS_RES`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(
+    ///     U256::MAX.checked_mul_widening::<64, 1, 320, 5>(U64::MAX),
+    ///     Some(U320::from(U256::MAX) * U320::from(U64::MAX))
+    /// );
+    /// assert_eq!(3_U8.checked_mul_widening::<8, 1, 4, 1>(5_U8), Some(15_U4));
+    /// assert_eq!(3_U8.checked_mul_widening::<8, 1, 4, 1>(6_U8), None);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[allow(clippy::similar_names)] // Don't confuse `res` and `rhs`.
+    pub fn checked_mul_widening<
+        const BITS_RHS: usize,
+        const LIMBS_RHS: usize,
+        const BITS_RES: usize,
+        const LIMBS_RES: usize,
+    >(
+        self,
+        rhs: Uint<BITS_RHS, LIMBS_RHS>,
+    ) -> Option<Uint<BITS_RES, LIMBS_RES>> {
+        let () = ResultWidth::<BITS_RES, LIMBS_RES>::OK;
+        let mut result = Uint::<BITS_RES, LIMBS_RES>::ZERO;
+        let mut overflow = algorithms::addmul(&mut result.limbs, self.as_limbs(), rhs.as_limbs());
+        if LIMBS_RES > 0 {
+            overflow |= result.limbs[LIMBS_RES - 1] > Uint::<BITS_RES, LIMBS_RES>::MASK;
+        }
+        if overflow {
+            None
+        } else {
+            Some(result)
+        }
+    }
+
+    /// Computes the exact `2 * BITS` bit product of two values of the same
+    /// width.
+    ///
+    /// This is [`Self::widening_mul`] specialized to `rhs: Self`. It is a
+    /// compile time error if `B2 != 2 * BITS` or `L2` does not match `B2`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(
+    ///     U128::MAX.full_mul::<256, 4>(U128::MAX),
+    ///     U256::from(U128::MAX) * U256::from(U128::MAX)
+    /// );
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn full_mul<const B2: usize, const L2: usize>(self, rhs: Self) -> Uint<B2, L2> {
+        let () = FullMulWidths::<BITS, B2, L2>::OK;
+        self.widening_mul(rhs)
+    }
+
+    /// Computes the high half of the full product, $\floor{\mathtt{self} ⋅
+    /// \mathtt{rhs} / 2^{\mathtt{BITS}}}$.
+    ///
+    /// Together with [`Self::wrapping_mul`], which returns the low half, this
+    /// gives the exact `2 * BITS` bit product without naming the wider type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::*};
+    /// # uint!{
+    /// assert_eq!(U256::MAX.mul_high(U256::MAX), U256::MAX - 1_U256);
+    /// assert_eq!(U256::MAX.wrapping_mul(U256::MAX), 1_U256);
+    /// assert_eq!(3_U64.mul_high(5_U64), 0_U64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn mul_high(self, rhs: Self) -> Self {
+        let mut product = [[0_u64; LIMBS]; 2];
+        // SAFETY: `[[u64; LIMBS]; 2]` has the same layout as `[u64; 2 * LIMBS]`.
+        let limbs =
+            unsafe { slice::from_raw_parts_mut(product.as_mut_ptr().cast::<u64>(), 2 * LIMBS) };
+        let overflow = algorithms::addmul(limbs, self.as_limbs(), rhs.as_limbs());
+        debug_assert!(!overflow);
+        algorithms::shift_right(limbs, BITS);
+        Self::from_limbs(product[0])
+    }
+}
+
+/// Compile time check of the widths in [`Uint::full_mul`].
+struct FullMulWidths<const BITS: usize, const B2: usize, const L2: usize>;
+
+impl<const BITS: usize, const B2: usize, const L2: usize> FullMulWidths<BITS, B2, L2> {
+    const OK: () = {
+        assert!(B2 == 2 * BITS, "B2 must be 2 * BITS");
+        assert!(L2 == nlimbs(B2), "L2 must be nlimbs(B2)");
+    };
+}
+
+/// Compile time check of the result width in [`Uint::checked_mul_widening`].
+struct ResultWidth<const BITS_RES: usize, const LIMBS_RES: usize>;
+
+impl<const BITS_RES: usize, const LIMBS_RES: usize> ResultWidth<BITS_RES, LIMBS_RES> {
+    const OK: () = assert!(
+        LIMBS_RES == nlimbs(BITS_RES),
+        "LIMBS_RES must be nlimbs(BITS_RES)"
+    );
 }
 
 impl<const BITS: usize, const LIMBS: usize> Product<Self> for Uint<BITS, LIMBS> {
@@ -180,7 +388,10 @@ impl_bin_op!(Mul, mul, MulAssign, mul_assign, wrapping_mul);
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::const_for;
+    use crate::{
+        aliases::{U128, U256},
+        const_for,
+    };
     use proptest::proptest;
 
     #[test]
@@ -228,6 +439,18 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_square() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U)| {
+                assert_eq!(a.overflowing_square(), a.overflowing_mul(a));
+                assert_eq!(a.wrapping_square(), a * a);
+            });
+        });
+    }
+
     #[test]
     fn test_inverse() {
         const_for!(BITS in NON_ZERO {
@@ -241,6 +464,65 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_mul_u64() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            const BITS_RES: usize = BITS + 64;
+            const LIMBS_RES: usize = nlimbs(BITS_RES);
+            type Res = Uint<BITS_RES, LIMBS_RES>;
+            proptest!(|(a: U, b: u64)| {
+                let (value, carry) = a.mul_u64(b);
+                let expected = Res::from(a) * Res::from(b);
+                assert_eq!(Res::from(value), expected & Res::from(U::MAX));
+                assert_eq!(Res::from(carry), expected >> BITS);
+                assert_eq!(a.wrapping_mul_u64(b), value);
+                assert_eq!(a.checked_mul_u64(b).is_some(), carry == 0);
+                if let Ok(small) = U::try_from(b) {
+                    assert_eq!(value, a.wrapping_mul(small));
+                    assert_eq!(a.checked_mul_u64(b), a.checked_mul(small));
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_mul_high() {
+        proptest!(|(a: U128, b: U128)| {
+            let full: U256 = a.widening_mul(b);
+            assert_eq!(a.mul_high(b), U128::from(full >> 128_usize));
+            assert_eq!(a.wrapping_mul(b), U128::wrapping_from(full));
+            assert_eq!(a.mul_high(b).is_zero(), !a.overflowing_mul(b).1);
+        });
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            const B2: usize = 2 * BITS;
+            const L2: usize = nlimbs(B2);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U)| {
+                let full = a.full_mul::<B2, L2>(b);
+                assert_eq!(a.mul_high(b), U::from(full >> BITS));
+                assert_eq!(a.mul_high(b), b.mul_high(a));
+            });
+        });
+    }
+
+    #[test]
+    fn test_full_mul() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            const BITS_RES: usize = 2 * BITS;
+            const LIMBS_RES: usize = nlimbs(BITS_RES);
+            type Res = Uint<BITS_RES, LIMBS_RES>;
+            assert_eq!(U::MAX.full_mul::<BITS_RES, LIMBS_RES>(U::MAX), Res::from(U::MAX) * Res::from(U::MAX));
+            proptest!(|(a: U, b: U)| {
+                assert_eq!(a.full_mul::<BITS_RES, LIMBS_RES>(b), Res::from(a) * Res::from(b));
+            });
+        });
+    }
+
     #[test]
     fn test_widening_mul() {
         // Left hand side
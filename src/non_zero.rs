@@ -0,0 +1,134 @@
+use crate::Uint;
+use core::ops::{Div, DivAssign, Rem, RemAssign};
+
+/// A [`Uint`] that is known not to be zero, like [`core::num::NonZeroU64`].
+///
+/// This encodes in the type that a divisor or modulus can not be zero, so
+/// division by it can not panic.
+///
+/// The [`Div`] and [`Rem`] impls use the same algorithms as `/` and `%` on
+/// [`Uint`], so they are exactly as fast. The algorithms have to find the most
+/// significant non-zero limb of the divisor anyway, and the zero check comes
+/// for free with that search.
+///
+/// Unlike the `core` non-zero types this does not have a niche:
+/// `Option<NonZeroUint>` is larger than [`Uint`]. Stable Rust has no way to
+/// declare that a multi-limb array is never all zeros.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "alloc", derive(Debug))]
+pub struct NonZeroUint<const BITS: usize, const LIMBS: usize>(Uint<BITS, LIMBS>);
+
+impl<const BITS: usize, const LIMBS: usize> NonZeroUint<BITS, LIMBS> {
+    /// Creates a non-zero value, returning [`None`] if `value` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, NonZeroUint, aliases::*};
+    /// # uint!{
+    /// assert!(NonZeroUint::new(0_U256).is_none());
+    /// assert_eq!(NonZeroUint::new(3_U256).unwrap().get(), 3_U256);
+    /// # }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new(value: Uint<BITS, LIMBS>) -> Option<Self> {
+        let mut i = 0;
+        while i < LIMBS {
+            if value.as_limbs()[i] != 0 {
+                return Some(Self(value));
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Returns the value as a [`Uint`].
+    #[must_use]
+    #[inline(always)]
+    pub const fn get(self) -> Uint<BITS, LIMBS> {
+        self.0
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<NonZeroUint<BITS, LIMBS>> for Uint<BITS, LIMBS> {
+    #[inline]
+    fn from(value: NonZeroUint<BITS, LIMBS>) -> Self {
+        value.0
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Div<NonZeroUint<BITS, LIMBS>> for Uint<BITS, LIMBS> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: NonZeroUint<BITS, LIMBS>) -> Self {
+        self / rhs.0
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Rem<NonZeroUint<BITS, LIMBS>> for Uint<BITS, LIMBS> {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, rhs: NonZeroUint<BITS, LIMBS>) -> Self {
+        self % rhs.0
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> DivAssign<NonZeroUint<BITS, LIMBS>>
+    for Uint<BITS, LIMBS>
+{
+    #[inline]
+    fn div_assign(&mut self, rhs: NonZeroUint<BITS, LIMBS>) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> RemAssign<NonZeroUint<BITS, LIMBS>>
+    for Uint<BITS, LIMBS>
+{
+    #[inline]
+    fn rem_assign(&mut self, rhs: NonZeroUint<BITS, LIMBS>) {
+        *self = *self % rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_new() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            assert_eq!(NonZeroUint::new(U::ZERO), None);
+            proptest!(|(value: U)| {
+                let non_zero = NonZeroUint::new(value);
+                assert_eq!(non_zero.is_some(), value != U::ZERO);
+                assert_eq!(non_zero.map(NonZeroUint::get), Some(value).filter(|v| *v != U::ZERO));
+            });
+        });
+    }
+
+    #[test]
+    fn test_div_rem() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U, d: U)| {
+                if let Some(nz) = NonZeroUint::new(d) {
+                    assert_eq!(n / nz, n / d);
+                    assert_eq!(n % nz, n % d);
+                    let (mut q, mut r) = (n, n);
+                    q /= nz;
+                    r %= nz;
+                    assert_eq!((q, r), n.div_rem(d));
+                }
+            });
+        });
+    }
+}
@@ -15,6 +15,9 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
 
     /// Raises self to the power of `exp` and if the result would overflow.
     ///
+    /// Uses exponentiation by squaring, so it takes $O(\log \mathtt{exp})$
+    /// multiplications.
+    ///
     /// # Examples
     ///
     /// ```
@@ -114,6 +117,34 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         result
     }
 
+    /// Returns $2^{\mathtt{exp}}$.
+    ///
+    /// This is a `const` alternative to `Self::from(1) << exp`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `exp >= BITS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::*};
+    /// # uint!{
+    /// const THRESHOLD: U256 = U256::pow2(128);
+    /// assert_eq!(THRESHOLD, 1_U256 << 128_usize);
+    /// assert_eq!(U64::pow2(0), 1_U64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn pow2(exp: usize) -> Self {
+        assert!(exp < BITS, "Uint::pow2 exponent out of range.");
+        let mut limbs = [0; LIMBS];
+        limbs[exp / 64] = 1 << (exp % 64);
+        Self::from_limbs(limbs)
+    }
+
     /// Construct from double precision binary logarithm.
     ///
     /// # Examples
@@ -195,6 +226,28 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_pow2() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            const ONE: U = U::pow2(0);
+            const TOP: U = U::pow2(BITS - 1);
+            assert_eq!(ONE, U::from(1));
+            assert_eq!(TOP, U::from(1) << (BITS - 1));
+            assert_eq!(TOP.leading_zeros(), 0);
+            proptest!(|(e in 0..BITS)| {
+                assert_eq!(U::pow2(e), U::from(1) << e);
+            });
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Uint::pow2 exponent out of range.")]
+    fn test_pow2_overflow() {
+        let _ = Uint::<65, 2>::pow2(65);
+    }
+
     #[test]
     fn test_pow_product() {
         const_for!(BITS in NON_ZERO if (BITS >= 64) {
@@ -207,4 +260,24 @@ mod tests {
             });
         });
     }
+
+    #[test]
+    fn test_overflowing_pow_naive() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(b: U, e in 0_usize..100)| {
+                let mut expected = (if BITS == 0 { U::ZERO } else { U::from(1) }, false);
+                for _ in 0..e {
+                    let (r, o) = expected.0.overflowing_mul(b);
+                    expected = (r, expected.1 | o);
+                }
+                if let Ok(e) = U::try_from(e) {
+                    assert_eq!(b.overflowing_pow(e), expected);
+                    assert_eq!(b.wrapping_pow(e), expected.0);
+                    assert_eq!(b.checked_pow(e), (!expected.1).then_some(expected.0));
+                }
+            });
+        });
+    }
 }
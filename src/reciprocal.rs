@@ -0,0 +1,194 @@
+use crate::{
+    algorithms::div::{div_2x1, div_3x2, reciprocal, reciprocal_2},
+    Uint,
+};
+
+/// A divisor with a precomputed reciprocal, for dividing many numerators by
+/// the same [`Uint`].
+///
+/// Division by a one or two limb divisor is dominated by normalizing the
+/// divisor and computing its reciprocal. This type does that once in
+/// [`Reciprocal::new`] and reuses it in every [`Reciprocal::div_rem`], which
+/// helps for example base conversion or reduction by a fixed modulus.
+///
+/// Larger divisors use Knuth division, where the reciprocal is a negligible
+/// part of the cost, so they fall back to [`Uint::div_rem`].
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "alloc", derive(Debug))]
+pub struct Reciprocal<const BITS: usize, const LIMBS: usize> {
+    divisor:    Uint<BITS, LIMBS>,
+    /// Number of nonzero limbs in `divisor`.
+    len:        usize,
+    /// Normalization shift of the top limb.
+    shift:      u32,
+    /// The one or two limb divisor shifted left by `shift`.
+    normalized: u128,
+    /// Reciprocal of `normalized`.
+    inverse:    u64,
+}
+
+impl<const BITS: usize, const LIMBS: usize> Reciprocal<BITS, LIMBS> {
+    /// Precomputes the reciprocal of `divisor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, Reciprocal, aliases::U256};
+    /// # uint!{
+    /// let ten = Reciprocal::new(10_U256);
+    /// assert_eq!(ten.div_rem(1234_U256), (123_U256, 4_U256));
+    /// assert_eq!(ten.div_rem(7_U256), (0_U256, 7_U256));
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn new(divisor: Uint<BITS, LIMBS>) -> Self {
+        let len = divisor
+            .as_limbs()
+            .iter()
+            .rposition(|&limb| limb != 0)
+            .expect("Divisor is zero")
+            + 1;
+        let limbs = divisor.as_limbs();
+        let (shift, normalized, inverse) = match len {
+            1 => {
+                let shift = limbs[0].leading_zeros();
+                let d = limbs[0] << shift;
+                (shift, u128::from(d), reciprocal(d))
+            }
+            2 => {
+                let shift = limbs[1].leading_zeros();
+                let d = ((u128::from(limbs[1]) << 64) | u128::from(limbs[0])) << shift;
+                (shift, d, reciprocal_2(d))
+            }
+            _ => (0, 0, 0),
+        };
+        Self {
+            divisor,
+            len,
+            shift,
+            normalized,
+            inverse,
+        }
+    }
+
+    /// Returns the divisor.
+    #[inline]
+    #[must_use]
+    pub const fn divisor(&self) -> Uint<BITS, LIMBS> {
+        self.divisor
+    }
+
+    /// Computes `numerator / divisor` and `numerator % divisor`.
+    ///
+    /// Same result as [`Uint::div_rem`] with [`Self::divisor`].
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // Intentional
+    pub fn div_rem(&self, numerator: Uint<BITS, LIMBS>) -> (Uint<BITS, LIMBS>, Uint<BITS, LIMBS>) {
+        let mut limbs = numerator.into_limbs();
+        let shift = self.shift;
+        // Limb `i` of the numerator shifted left by `shift`. Reads limb
+        // `i - 1` before it is overwritten with the quotient.
+        let shifted = |limbs: &[u64; LIMBS], i: usize| {
+            if shift == 0 {
+                limbs[i]
+            } else if i == 0 {
+                limbs[0] << shift
+            } else {
+                (limbs[i] << shift) | (limbs[i - 1] >> (64 - shift))
+            }
+        };
+        let top = if shift == 0 {
+            0
+        } else {
+            limbs[LIMBS - 1] >> (64 - shift)
+        };
+        match self.len {
+            1 => {
+                let d = self.normalized as u64;
+                let mut remainder = top;
+                for i in (0..LIMBS).rev() {
+                    let n = (u128::from(remainder) << 64) | u128::from(shifted(&limbs, i));
+                    let (q, r) = div_2x1(n, d, self.inverse);
+                    limbs[i] = q;
+                    remainder = r;
+                }
+                (Uint::from_limbs(limbs), Uint::from(remainder >> shift))
+            }
+            2 => {
+                let mut remainder = u128::from(top);
+                for i in (0..LIMBS).rev() {
+                    let u = shifted(&limbs, i);
+                    let (q, r) = div_3x2(remainder, u, self.normalized, self.inverse);
+                    limbs[i] = q;
+                    remainder = r;
+                }
+                (Uint::from_limbs(limbs), Uint::from(remainder >> shift))
+            }
+            _ => numerator.div_rem(self.divisor),
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for Reciprocal<BITS, LIMBS> {
+    #[inline]
+    #[track_caller]
+    fn from(divisor: Uint<BITS, LIMBS>) -> Self {
+        Self::new(divisor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::{prop_assume, proptest};
+
+    #[test]
+    #[allow(clippy::modulo_one)] // From macro.
+    fn test_div_rem() {
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(n: U, d: U, shift: usize)| {
+                // Cover one, two and more limb divisors.
+                let d = d >> (shift % BITS);
+                prop_assume!(!d.is_zero());
+                let reciprocal = Reciprocal::new(d);
+                assert_eq!(reciprocal.divisor(), d);
+                assert_eq!(reciprocal.div_rem(n), n.div_rem(d));
+            });
+            proptest!(|(n: U, d: u64)| {
+                let d = U::wrapping_from(d);
+                prop_assume!(!d.is_zero());
+                assert_eq!(Reciprocal::new(d).div_rem(n), n.div_rem(d));
+            });
+        });
+    }
+
+    #[test]
+    fn test_repeated() {
+        let ten = Reciprocal::new(Uint::<256, 4>::from(10));
+        let mut n = Uint::<256, 4>::MAX;
+        let mut digits = 0;
+        while !n.is_zero() {
+            let (q, r) = ten.div_rem(n);
+            assert_eq!(r, n % Uint::from(10));
+            n = q;
+            digits += 1;
+        }
+        assert_eq!(digits, 78);
+    }
+
+    #[test]
+    #[should_panic(expected = "Divisor is zero")]
+    fn test_zero() {
+        let _ = Reciprocal::new(Uint::<64, 1>::ZERO);
+    }
+}
@@ -63,6 +63,62 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         }
         Some(Self::from(1) << exp)
     }
+
+    /// Returns the largest power of two less than or equal to `self`, or zero
+    /// if `self` is zero.
+    ///
+    /// This is the `std::bit_floor` function from C++20.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::U64};
+    /// # uint!{
+    /// assert_eq!(0_U64.bit_floor(), 0_U64);
+    /// assert_eq!(1_U64.bit_floor(), 1_U64);
+    /// assert_eq!(5_U64.bit_floor(), 4_U64);
+    /// assert_eq!(U64::MAX.bit_floor(), 1_U64 << 63);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn bit_floor(self) -> Self {
+        match self.bit_len() {
+            0 => Self::ZERO,
+            len => Self::from(1) << (len - 1),
+        }
+    }
+
+    /// Returns the smallest power of two greater than or equal to `self`.
+    ///
+    /// This is the `std::bit_ceil` function from C++20. It differs from
+    /// [`Self::next_power_of_two`] only in name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit in `BITS` bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{Uint, uint, aliases::U64};
+    /// # uint!{
+    /// assert_eq!(0_U64.bit_ceil(), 1_U64);
+    /// assert_eq!(1_U64.bit_ceil(), 1_U64);
+    /// assert_eq!(5_U64.bit_ceil(), 8_U64);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn bit_ceil(self) -> Self {
+        if self <= Self::from(1) {
+            return Self::from(1);
+        }
+        let len = (self - Self::from(1)).bit_len();
+        assert!(len < BITS, "Value too large for bit_ceil.");
+        Self::from(1) << len
+    }
 }
 
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
@@ -75,15 +131,18 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// overflow.
     #[inline]
     #[must_use]
+    #[track_caller]
     pub fn next_multiple_of(self, rhs: Self) -> Self {
-        self.checked_next_multiple_of(rhs).unwrap();
-        todo!()
+        self.checked_next_multiple_of(rhs).unwrap()
     }
 
     /// Calculates the smallest value greater than or equal to `self` that is a
     /// multiple of `rhs`. Returns [`None`] is `rhs` is zero or the
     /// operation would result in overflow.
     ///
+    /// If `rhs` is a power of two this rounds up with a bit mask instead of a
+    /// division.
+    ///
     /// # Examples
     ///
     /// ```
@@ -112,6 +171,16 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         if rhs.is_zero() {
             return None;
         }
+        if rhs.is_power_of_two() {
+            let mask = rhs - Self::from(1);
+            return self.checked_add(mask).map(|n| n & !mask);
+        }
+        self.checked_next_multiple_of_div(rhs)
+    }
+
+    /// General case of [`Self::checked_next_multiple_of`] for a nonzero `rhs`.
+    #[inline]
+    fn checked_next_multiple_of_div(self, rhs: Self) -> Option<Self> {
         let (q, r) = self.div_rem(rhs);
         if r.is_zero() {
             return Some(self);
@@ -120,3 +189,70 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         q.checked_mul(rhs)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_bit_floor_ceil() {
+        proptest!(|(n: u64)| {
+            let value = Uint::<64, 1>::from(n);
+            let floor = if n == 0 { 0 } else { 1_u64 << (63 - n.leading_zeros()) };
+            assert_eq!(value.bit_floor(), Uint::from(floor));
+            if let Some(ceil) = n.checked_next_power_of_two() {
+                assert_eq!(value.bit_ceil(), Uint::from(ceil));
+            }
+        });
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let floor = value.bit_floor();
+                assert!(floor <= value);
+                if !value.is_zero() {
+                    assert!(floor.is_power_of_two());
+                    assert!(value.checked_shr(1).unwrap_or_default() < floor);
+                }
+                if let Some(ceil) = value.checked_next_power_of_two() {
+                    assert_eq!(value.bit_ceil(), ceil);
+                }
+            });
+        });
+    }
+
+    #[test]
+    #[allow(clippy::modulo_one)] // From macro.
+    fn test_next_multiple_of() {
+        proptest!(|(n: u64, rhs: u64)| {
+            let value = Uint::<64, 1>::from(n);
+            let expected = (rhs != 0)
+                .then(|| (u128::from(n) + u128::from(rhs) - 1) / u128::from(rhs) * u128::from(rhs))
+                .and_then(|m| u64::try_from(m).ok())
+                .map(Uint::from);
+            assert_eq!(value.checked_next_multiple_of(Uint::from(rhs)), expected);
+            if let Some(expected) = expected {
+                assert_eq!(value.next_multiple_of(Uint::from(rhs)), expected);
+            }
+        });
+        const_for!(BITS in NON_ZERO {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U, shift: usize)| {
+                let rhs = U::from(1) << (shift % BITS);
+                assert_eq!(
+                    value.checked_next_multiple_of(rhs),
+                    value.checked_next_multiple_of_div(rhs)
+                );
+            });
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "called `Option::unwrap()` on a `None` value")]
+    fn test_next_multiple_of_zero() {
+        let _ = Uint::<64, 1>::from(1).next_multiple_of(Uint::ZERO);
+    }
+}
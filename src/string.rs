@@ -3,6 +3,9 @@
 use crate::{base_convert::BaseConvertError, Uint};
 use core::{fmt, str::FromStr};
 
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+
 /// Error for [`from_str_radix`](Uint::from_str_radix).
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ParseError {
@@ -44,17 +47,62 @@ impl fmt::Display for ParseError {
     }
 }
 
+/// Error for [`checked_from_str_radix`](Uint::checked_from_str_radix).
+///
+/// Unlike [`ParseError`], each variant carries enough context to produce a
+/// user-facing message without knowing the target type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FromStrRadixError {
+    /// The string contains no digits.
+    Empty,
+
+    /// Invalid radix, bases 2 to 64 are supported.
+    InvalidRadix(u64),
+
+    /// The character `c` is not a valid digit in `base`.
+    InvalidDigit {
+        /// The offending character.
+        c:    char,
+        /// The radix the string was parsed in.
+        base: u64,
+    },
+
+    /// The value does not fit in a [`Uint`] of `bits` bits.
+    Overflow {
+        /// The bit width of the target type.
+        bits: usize,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromStrRadixError {}
+
+impl fmt::Display for FromStrRadixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("cannot parse integer from empty string"),
+            Self::InvalidRadix(r) => write!(f, "invalid radix {r}, 2 to 64 are supported"),
+            Self::InvalidDigit { c, base } => write!(f, "invalid digit {c:?} for base {base}"),
+            Self::Overflow { bits } => write!(f, "number too large to fit in {bits} bits"),
+        }
+    }
+}
+
 impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
     /// Parse a string into a [`Uint`].
     ///
     /// For bases 2 to 36, the case-agnostic alphabet 0—1, a—b is used and `_`
-    /// are ignored. For bases 37 to 64, the case-sensitive alphabet a—z, A—Z,
-    /// 0—9, {+-}, {/,_} is used. That is, for base 64 it is compatible with
-    /// all the common base64 variants.
+    /// separators are ignored. Each separator must be between two digits, so
+    /// `1_000` is accepted but `_1`, `1_` and `1__0` are not. This is stricter
+    /// than Rust literals, and with [`FromStr`] it also rejects a separator
+    /// right after the prefix, as in `0x_ff`. For bases 37 to 64, the
+    /// case-sensitive alphabet a—z, A—Z, 0—9, {+-}, {/,_} is used. That is, for
+    /// base 64 it is compatible with all the common base64 variants.
     ///
     /// # Errors
     ///
-    /// * [`ParseError::InvalidDigit`] if the string contains a non-digit.
+    /// * [`ParseError::InvalidDigit`] if the string contains a non-digit or a
+    ///   misplaced `_` separator.
     /// * [`ParseError::InvalidRadix`] if the radix is larger than 64.
     /// * [`ParseError::BaseConvertError`] if [`Uint::from_base_be`] fails.
     // FEATURE: Support proper unicode. Ignore zero-width spaces, joiners, etc.
@@ -63,43 +111,173 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         if radix > 64 {
             return Err(ParseError::InvalidRadix(radix));
         }
+        if !valid_separators(src, radix) {
+            return Err(ParseError::InvalidDigit('_'));
+        }
         let mut err = None;
         let digits = src.chars().filter_map(|c| {
             if err.is_some() {
                 return None;
             }
-            let digit = if radix <= 36 {
-                // Case insensitive 0—9, a—z.
-                match c {
-                    '0'..='9' => u64::from(c) - u64::from('0'),
-                    'a'..='z' => u64::from(c) - u64::from('a') + 10,
-                    'A'..='Z' => u64::from(c) - u64::from('A') + 10,
-                    '_' => return None, // Ignored character.
-                    _ => {
-                        err = Some(ParseError::InvalidDigit(c));
-                        return None;
-                    }
-                }
-            } else {
-                // The Base-64 alphabets
-                match c {
-                    'A'..='Z' => u64::from(c) - u64::from('A'),
-                    'a'..='f' => u64::from(c) - u64::from('a') + 26,
-                    '0'..='9' => u64::from(c) - u64::from('0') + 52,
-                    '+' | '-' => 62,
-                    '/' | ',' | '_' => 63,
-                    '=' | '\r' | '\n' => return None, // Ignored characters.
-                    _ => {
-                        err = Some(ParseError::InvalidDigit(c));
-                        return None;
-                    }
-                }
-            };
-            Some(digit)
+            parse_digit(c, radix).unwrap_or_else(|()| {
+                err = Some(ParseError::InvalidDigit(c));
+                None
+            })
         });
         let value = Self::from_base_be(radix, digits)?;
         err.map_or(Ok(value), Err)
     }
+
+    /// Parse a string into a [`Uint`] with a descriptive error.
+    ///
+    /// Accepts the same alphabets as [`Uint::from_str_radix`], but reports
+    /// the offending character and base for invalid digits (including digits
+    /// that are in the alphabet but out of range for `radix`) and the target
+    /// width on overflow.
+    ///
+    /// # Errors
+    ///
+    /// * [`FromStrRadixError::InvalidRadix`] if the radix is not in `2..=64`.
+    /// * [`FromStrRadixError::Empty`] if the string contains no digits.
+    /// * [`FromStrRadixError::InvalidDigit`] if a character is not a valid
+    ///   digit in `radix` or is a misplaced `_` separator.
+    /// * [`FromStrRadixError::Overflow`] if the value does not fit in `BITS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{aliases::*, FromStrRadixError};
+    /// assert_eq!(U8::checked_from_str_radix("ff", 16), Ok(U8::from(255)));
+    /// assert_eq!(
+    ///     U8::checked_from_str_radix("19", 8),
+    ///     Err(FromStrRadixError::InvalidDigit { c: '9', base: 8 })
+    /// );
+    /// assert_eq!(
+    ///     U8::checked_from_str_radix("100", 16),
+    ///     Err(FromStrRadixError::Overflow { bits: 8 })
+    /// );
+    /// ```
+    pub fn checked_from_str_radix(src: &str, radix: u64) -> Result<Self, FromStrRadixError> {
+        if !(2..=64).contains(&radix) {
+            return Err(FromStrRadixError::InvalidRadix(radix));
+        }
+        if !valid_separators(src, radix) {
+            return Err(FromStrRadixError::InvalidDigit {
+                c:    '_',
+                base: radix,
+            });
+        }
+        let mut empty = true;
+        for c in src.chars() {
+            match parse_digit(c, radix) {
+                Ok(None) => {}
+                Ok(Some(digit)) if digit < radix => empty = false,
+                _ => return Err(FromStrRadixError::InvalidDigit { c, base: radix }),
+            }
+        }
+        if empty {
+            return Err(FromStrRadixError::Empty);
+        }
+        let digits = src
+            .chars()
+            .filter_map(|c| parse_digit(c, radix).ok().flatten());
+        Self::from_base_be(radix, digits).map_err(|err| {
+            // Radix and digits have been validated above.
+            debug_assert_eq!(err, BaseConvertError::Overflow);
+            FromStrRadixError::Overflow { bits: BITS }
+        })
+    }
+
+    /// Parse a hexadecimal string into a [`Uint`].
+    ///
+    /// An optional `0x` or `0X` prefix is stripped, the remaining digits are
+    /// case-insensitive and may be of any length, including odd. Like
+    /// [`Uint::from_str_radix`], `_` separators are allowed between digits and
+    /// an empty string parses as zero.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Uint::from_str_radix`] with radix 16:
+    ///
+    /// * [`ParseError::InvalidDigit`] if the string contains a character
+    ///   outside the alphanumeric alphabet.
+    /// * [`ParseError::BaseConvertError`] if a letter beyond `f` is found or
+    ///   the value does not fit in `BITS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::*;
+    /// assert_eq!(
+    ///     U256::from_hex_str("0xdeadbeef"),
+    ///     Ok(U256::from(0xdead_beef_u64))
+    /// );
+    /// assert_eq!(U256::from_hex_str("abc"), Ok(U256::from(0xabc)));
+    /// assert!(U8::from_hex_str("100").is_err());
+    /// ```
+    pub fn from_hex_str(src: &str) -> Result<Self, ParseError> {
+        let src = src
+            .strip_prefix("0x")
+            .or_else(|| src.strip_prefix("0X"))
+            .unwrap_or(src);
+        Self::from_str_radix(src, 16)
+    }
+
+    /// Format the value as a lowercase hexadecimal string.
+    ///
+    /// The result has no `0x` prefix and minimal width, i.e. no leading zeros
+    /// except for zero itself which is `"0"`. Use the
+    /// [`LowerHex`](fmt::LowerHex) formatting options, e.g.
+    /// `format!("{value:064x}")`, for fixed widths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::aliases::*;
+    /// assert_eq!(U256::from(0xdead_beef_u64).to_hex_string(), "deadbeef");
+    /// assert_eq!(U256::ZERO.to_hex_string(), "0");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_hex_string(&self) -> String {
+        format!("{self:x}")
+    }
+}
+
+/// Checks that `_` separators are only used between digits. In bases above 36
+/// `_` is a digit and can be anywhere.
+fn valid_separators(src: &str, radix: u64) -> bool {
+    radix > 36 || !(src.starts_with('_') || src.ends_with('_') || src.contains("__"))
+}
+
+/// Maps a character to its digit value in `radix`, see
+/// [`Uint::from_str_radix`] for the alphabets.
+///
+/// Returns `Ok(None)` for ignored characters and `Err(())` for characters
+/// outside the alphabet. The digit is not checked against `radix`.
+fn parse_digit(c: char, radix: u64) -> Result<Option<u64>, ()> {
+    let digit = if radix <= 36 {
+        // Case insensitive 0—9, a—z.
+        match c {
+            '0'..='9' => u64::from(c) - u64::from('0'),
+            'a'..='z' => u64::from(c) - u64::from('a') + 10,
+            'A'..='Z' => u64::from(c) - u64::from('A') + 10,
+            '_' => return Ok(None), // Ignored character.
+            _ => return Err(()),
+        }
+    } else {
+        // The Base-64 alphabets
+        match c {
+            'A'..='Z' => u64::from(c) - u64::from('A'),
+            'a'..='f' => u64::from(c) - u64::from('a') + 26,
+            '0'..='9' => u64::from(c) - u64::from('0') + 52,
+            '+' | '-' => 62,
+            '/' | ',' | '_' => 63,
+            '=' | '\r' | '\n' => return Ok(None), // Ignored characters.
+            _ => return Err(()),
+        }
+    };
+    Ok(Some(digit))
 }
 
 impl<const BITS: usize, const LIMBS: usize> FromStr for Uint<BITS, LIMBS> {
@@ -135,6 +313,118 @@ mod tests {
             prop_assert_eq!(U::from_str(&format!("{value:}")), Ok(U::from(value)));
             prop_assert_eq!(U::from_str(&format!("{value:#x}")), Ok(U::from(value)));
             prop_assert_eq!(U::from_str(&format!("{value:#X}")), Ok(U::from(value)));
+            prop_assert_eq!(U::checked_from_str_radix(&format!("{value:x}"), 16), Ok(U::from(value)));
+        });
+    }
+
+    #[test]
+    fn test_hex_str() {
+        type U = Uint<64, 1>;
+        proptest!(|(value: u64)| {
+            let uint = U::from(value);
+            prop_assert_eq!(uint.to_hex_string(), format!("{value:x}"));
+            prop_assert_eq!(U::from_hex_str(&uint.to_hex_string()), Ok(uint));
+            prop_assert_eq!(U::from_hex_str(&format!("{value:#x}")), Ok(uint));
+            prop_assert_eq!(U::from_hex_str(&format!("{value:#X}").replace('X', "x")), Ok(uint));
         });
+        assert_eq!(U::from_hex_str("abc"), Ok(U::from(0xabc)));
+        assert_eq!(U::from_hex_str("0Xabc"), Ok(U::from(0xabc)));
+        assert_eq!(U::from_hex_str("0x0abc"), Ok(U::from(0xabc)));
+        assert_eq!(U::from_hex_str("0xABC"), Ok(U::from(0xabc)));
+        assert_eq!(U::from_hex_str("0x"), Ok(U::ZERO));
+        assert_eq!(
+            U::from_hex_str("0xg"),
+            Err(ParseError::BaseConvertError(
+                BaseConvertError::InvalidDigit(16, 16)
+            ))
+        );
+        assert_eq!(U::from_hex_str("0x-1"), Err(ParseError::InvalidDigit('-')));
+        assert_eq!(U::from_hex_str("ffffffffffffffff"), Ok(U::MAX));
+        assert_eq!(
+            U::from_hex_str("1_0000_0000_0000_0000"),
+            Err(ParseError::BaseConvertError(BaseConvertError::Overflow))
+        );
+        assert_eq!(U::ZERO.to_hex_string(), "0");
+        assert_eq!(Uint::<0, 0>::ZERO.to_hex_string(), "0");
+    }
+
+    #[test]
+    fn test_checked_from_str_radix() {
+        type U = Uint<64, 1>;
+        assert_eq!(U::checked_from_str_radix("1_000", 10), Ok(U::from(1000)));
+        assert_eq!(
+            U::checked_from_str_radix("", 10),
+            Err(FromStrRadixError::Empty)
+        );
+        assert_eq!(
+            U::checked_from_str_radix("__", 10),
+            Err(FromStrRadixError::InvalidDigit {
+                c:    '_',
+                base: 10,
+            })
+        );
+        assert_eq!(
+            U::checked_from_str_radix("12", 1),
+            Err(FromStrRadixError::InvalidRadix(1))
+        );
+        assert_eq!(
+            U::checked_from_str_radix("12", 65),
+            Err(FromStrRadixError::InvalidRadix(65))
+        );
+        assert_eq!(
+            U::checked_from_str_radix("12z", 16),
+            Err(FromStrRadixError::InvalidDigit {
+                c:    'z',
+                base: 16,
+            })
+        );
+        assert_eq!(
+            U::checked_from_str_radix("1 2", 10),
+            Err(FromStrRadixError::InvalidDigit {
+                c:    ' ',
+                base: 10,
+            })
+        );
+        assert_eq!(
+            U::checked_from_str_radix("18446744073709551615", 10),
+            Ok(U::MAX)
+        );
+        assert_eq!(
+            U::checked_from_str_radix("18446744073709551616", 10),
+            Err(FromStrRadixError::Overflow { bits: 64 })
+        );
+        assert_eq!(
+            Uint::<0, 0>::checked_from_str_radix("1", 10),
+            Err(FromStrRadixError::Overflow { bits: 0 })
+        );
+    }
+
+    #[test]
+    fn test_separators() {
+        type U = Uint<64, 1>;
+        for valid in ["1_000_000", "1_0_0_0_0_0_0", "1000000", "0xf_4240"] {
+            assert_eq!(U::from_str(valid), Ok(U::from(1_000_000)), "{valid}");
+        }
+        assert_eq!(U::from_str_radix("f_ff", 16), Ok(U::from(0xfff)));
+        assert_eq!(U::from_hex_str("0xdead_beef"), Ok(U::from(0xdead_beef_u64)));
+        for invalid in ["_123", "123_", "1__2", "_", "__", "0b_", "0x_1", "0x1__0"] {
+            assert_eq!(
+                U::from_str(invalid),
+                Err(ParseError::InvalidDigit('_')),
+                "{invalid}"
+            );
+            let digits = invalid.trim_start_matches("0x").trim_start_matches("0b");
+            assert_eq!(
+                U::checked_from_str_radix(digits, 16),
+                Err(FromStrRadixError::InvalidDigit {
+                    c:    '_',
+                    base: 16,
+                }),
+                "{invalid}"
+            );
+        }
+        // In base 64 `_` is a digit.
+        assert_eq!(U::from_str_radix("_", 64), Ok(U::from(63)));
+        assert_eq!(U::from_str_radix("__", 64), Ok(U::from(63 * 64 + 63)));
     }
 }
@@ -13,27 +13,53 @@ use ark_ff_04::{
 
 // BigInt
 
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Converts an [`BigInt`] with the same limb count by moving its
+    /// limb array, without a byte round-trip.
+    ///
+    /// Both types store little-endian `u64` limbs, so this is a plain copy of
+    /// the array and can be used in `const` contexts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is too large for the bit-size of the Uint.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn from_ark_bigint(value: BigInt<LIMBS>) -> Self {
+        Self::from_limbs(value.0)
+    }
+
+    /// Returns the value as an [`BigInt`] with the same limb count,
+    /// without a byte round-trip.
+    #[inline]
+    #[must_use]
+    pub const fn as_ark_bigint(&self) -> BigInt<LIMBS> {
+        BigInt(self.limbs)
+    }
+}
+
 impl<const BITS: usize, const LIMBS: usize> From<BigInt<LIMBS>> for Uint<BITS, LIMBS> {
     fn from(value: BigInt<LIMBS>) -> Self {
-        Self::from_limbs(value.0)
+        Self::from_ark_bigint(value)
     }
 }
 
 impl<const BITS: usize, const LIMBS: usize> From<&BigInt<LIMBS>> for Uint<BITS, LIMBS> {
     fn from(value: &BigInt<LIMBS>) -> Self {
-        Self::from_limbs(value.0)
+        Self::from_ark_bigint(*value)
     }
 }
 
 impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for BigInt<LIMBS> {
     fn from(value: Uint<BITS, LIMBS>) -> Self {
-        Self::new(value.into_limbs())
+        value.as_ark_bigint()
     }
 }
 
 impl<const BITS: usize, const LIMBS: usize> From<&Uint<BITS, LIMBS>> for BigInt<LIMBS> {
     fn from(value: &Uint<BITS, LIMBS>) -> Self {
-        Self::new(value.into_limbs())
+        value.as_ark_bigint()
     }
 }
 
@@ -106,6 +132,35 @@ mod tests {
         test_roundtrip!(BigInteger832, 832, 13);
     }
 
+    #[test]
+    fn test_ark_bigint_field_widths() {
+        // BN254 base and scalar fields.
+        const FQ: Uint<254, 4> = Uint::from_ark_bigint(FqConfig::MODULUS);
+        const FR: Uint<254, 4> = Uint::from_ark_bigint(FrConfig::MODULUS);
+        // BLS12-381 base field.
+        const BLS_FQ: Uint<381, 6> = Uint::from_ark_bigint(BigInt([
+            0xb9fe_ffff_ffff_aaab,
+            0x1eab_fffe_b153_ffff,
+            0x6730_d2a0_f6b0_f624,
+            0x6477_4b84_f385_12bf,
+            0x4b1b_a7b6_434b_acd7,
+            0x1a01_11ea_397f_e69a,
+        ]));
+
+        assert_eq!(FQ.as_ark_bigint(), FqConfig::MODULUS);
+        assert_eq!(FR.as_ark_bigint(), FrConfig::MODULUS);
+        proptest!(|(value: Uint<254, 4>)| {
+            assert_eq!(Uint::from_ark_bigint(value.as_ark_bigint()), value);
+        });
+
+        assert_eq!(BLS_FQ.bit_len(), 381);
+        assert_eq!(BLS_FQ.as_ark_bigint(), BigInt::<6>::from(BLS_FQ));
+        proptest!(|(value: Uint<381, 6>)| {
+            assert_eq!(Uint::from_ark_bigint(value.as_ark_bigint()), value);
+            assert_eq!(value.as_ark_bigint(), BigInt::<6>::from(value));
+        });
+    }
+
     #[test]
     fn test_fq_roundtrip() {
         let modulus: U256 = FqConfig::MODULUS.into();
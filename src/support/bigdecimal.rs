@@ -0,0 +1,239 @@
+//! Support for the [`bigdecimal`](https://crates.io/crates/bigdecimal) crate.
+
+#![cfg(feature = "bigdecimal")]
+#![cfg_attr(docsrs, doc(cfg(feature = "bigdecimal")))]
+
+use crate::Uint;
+use bigdecimal::{
+    num_bigint::{BigInt, Sign},
+    BigDecimal,
+};
+use core::fmt;
+
+/// Error for [`TryFrom<BigDecimal>`][TryFrom] for [`Uint`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FromBigDecimalError {
+    /// Negative values can not be represented as Uint.
+    ValueNegative,
+
+    /// Value is too large to fit the Uint.
+    ValueTooLarge,
+
+    /// Values with a nonzero fractional part can not be represented as Uint.
+    ValueFractional,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromBigDecimalError {}
+
+impl fmt::Display for FromBigDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ValueNegative => f.write_str("Negative values cannot be represented as Uint"),
+            Self::ValueTooLarge => f.write_str("Value is too large for Uint"),
+            Self::ValueFractional => f.write_str("Fractional values cannot be represented as Uint"),
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Returns the value divided by $10^{\mathtt{scale}}$ as a [`BigDecimal`].
+    ///
+    /// This is the fixed-point reading of an integer amount, for example a
+    /// balance in wei shown in ether with `scale = 18`. The conversion is
+    /// exact and keeps all `scale` fractional digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U256};
+    /// # uint!{
+    /// let wei = 1_500_000_000_000_000_000_U256;
+    /// assert_eq!(wei.to_bigdecimal_scaled(18).to_string(), "1.500000000000000000");
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_bigdecimal_scaled(&self, scale: i64) -> BigDecimal {
+        BigDecimal::new(
+            BigInt::from_bytes_le(Sign::Plus, &self.as_le_bytes()),
+            scale,
+        )
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<Uint<BITS, LIMBS>> for BigDecimal {
+    fn from(value: Uint<BITS, LIMBS>) -> Self {
+        value.to_bigdecimal_scaled(0)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> From<&Uint<BITS, LIMBS>> for BigDecimal {
+    fn from(value: &Uint<BITS, LIMBS>) -> Self {
+        value.to_bigdecimal_scaled(0)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> TryFrom<BigDecimal> for Uint<BITS, LIMBS> {
+    type Error = FromBigDecimalError;
+
+    fn try_from(value: BigDecimal) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+/// Converts a non-negative integral [`BigDecimal`].
+///
+/// The cost is bounded by the size of the digits and `BITS`, not by the
+/// exponent, so this is safe for untrusted input like `1e1000000000`.
+///
+/// # Errors
+///
+/// * [`FromBigDecimalError::ValueNegative`] if the value is negative.
+/// * [`FromBigDecimalError::ValueTooLarge`] if the value does not fit.
+/// * [`FromBigDecimalError::ValueFractional`] if the value has a nonzero
+///   fractional part.
+impl<const BITS: usize, const LIMBS: usize> TryFrom<&BigDecimal> for Uint<BITS, LIMBS> {
+    type Error = FromBigDecimalError;
+
+    fn try_from(value: &BigDecimal) -> Result<Self, Self::Error> {
+        match value.sign() {
+            Sign::Minus => return Err(FromBigDecimalError::ValueNegative),
+            Sign::NoSign => return Ok(Self::ZERO),
+            Sign::Plus => {}
+        }
+        // Reject on the number of integer digits before rescaling, which would
+        // compute a power of ten as large as the exponent. The bound is at
+        // least the number of decimal digits of `Self::MAX`.
+        let scale = value.fractional_digit_count();
+        let max_digits = i128::try_from(BITS).unwrap_or(i128::MAX) * 30103 / 100_000 + 1;
+        let int_digits = i128::from(value.digits()) - i128::from(scale);
+        if int_digits > max_digits {
+            return Err(FromBigDecimalError::ValueTooLarge);
+        }
+        if int_digits <= 0 {
+            // A nonzero value below one.
+            return Err(FromBigDecimalError::ValueFractional);
+        }
+        // Now `-scale < max_digits` and `scale < value.digits()`, so rescaling
+        // is bounded. It truncates towards zero.
+        let (int, _) = value.with_scale(0).into_bigint_and_exponent();
+        let (_, digits) = int.to_u64_digits();
+        let (n, overflow) = Self::overflowing_from_limbs_slice(digits.as_slice());
+        if overflow {
+            Err(FromBigDecimalError::ValueTooLarge)
+        } else if !value.is_integer() {
+            Err(FromBigDecimalError::ValueFractional)
+        } else {
+            Ok(n)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U256, const_for, nlimbs};
+    use core::str::FromStr;
+    use proptest::proptest;
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let big: BigDecimal = value.into();
+                assert_eq!(big.to_string(), value.to_string());
+                let back: U = big.try_into().unwrap();
+                assert_eq!(back, value);
+            });
+        });
+    }
+
+    #[test]
+    fn test_scaled() {
+        let wei = U256::from(1_234_500_000_000_000_000_u64);
+        assert_eq!(
+            wei.to_bigdecimal_scaled(18).to_string(),
+            "1.234500000000000000"
+        );
+        assert_eq!(
+            wei.to_bigdecimal_scaled(18).normalized().to_string(),
+            "1.2345"
+        );
+        assert_eq!(U256::from(5).to_bigdecimal_scaled(2).to_string(), "0.05");
+        assert_eq!(
+            U256::from(5).to_bigdecimal_scaled(-2),
+            BigDecimal::from(500)
+        );
+        assert_eq!(U256::ZERO.to_bigdecimal_scaled(18), BigDecimal::from(0));
+        proptest!(|(value: U256, scale in -40_i64..40)| {
+            let scaled = value.to_bigdecimal_scaled(scale);
+            assert_eq!(scaled.fractional_digit_count(), scale);
+            let ten_pow = BigDecimal::new(1.into(), -scale);
+            assert_eq!(U256::try_from(scaled * ten_pow), Ok(value));
+        });
+    }
+
+    #[test]
+    fn test_reject() {
+        type U = Uint<8, 1>;
+        let parse = |s: &str| U::try_from(BigDecimal::from_str(s).unwrap());
+        assert_eq!(parse("12.000"), Ok(U::from(12)));
+        assert_eq!(parse("1.2e1"), Ok(U::from(12)));
+        assert_eq!(parse("0.000"), Ok(U::ZERO));
+        assert_eq!(parse("0e1000000000"), Ok(U::ZERO));
+        assert_eq!(parse("255"), Ok(U::MAX));
+        assert_eq!(parse("12.5"), Err(FromBigDecimalError::ValueFractional));
+        assert_eq!(parse("0.001"), Err(FromBigDecimalError::ValueFractional));
+        assert_eq!(parse("-3"), Err(FromBigDecimalError::ValueNegative));
+        assert_eq!(parse("-0.5"), Err(FromBigDecimalError::ValueNegative));
+        assert_eq!(parse("256"), Err(FromBigDecimalError::ValueTooLarge));
+        assert_eq!(parse("257.5"), Err(FromBigDecimalError::ValueTooLarge));
+        assert_eq!(parse("999"), Err(FromBigDecimalError::ValueTooLarge));
+        assert_eq!(parse("1000"), Err(FromBigDecimalError::ValueTooLarge));
+    }
+
+    #[test]
+    fn test_large_exponent() {
+        // These would compute `10^1000000000` if rescaled naively.
+        let parse = |s: &str| U256::try_from(BigDecimal::from_str(s).unwrap());
+        assert_eq!(
+            parse("1e1000000000"),
+            Err(FromBigDecimalError::ValueTooLarge)
+        );
+        assert_eq!(
+            parse("1e-1000000000"),
+            Err(FromBigDecimalError::ValueFractional)
+        );
+        assert_eq!(
+            parse("-1e1000000000"),
+            Err(FromBigDecimalError::ValueNegative)
+        );
+        assert_eq!(
+            parse("123456789e-1000000000"),
+            Err(FromBigDecimalError::ValueFractional)
+        );
+        assert_eq!(parse("1e77"), Ok(U256::from(10).pow(U256::from(77))));
+        assert_eq!(parse("1e78"), Err(FromBigDecimalError::ValueTooLarge));
+        assert_eq!(parse("100e-2"), Ok(U256::from(1)));
+        assert_eq!(
+            parse("115792089237316195423570985008687907853269984665640564039457584007913129639935"),
+            Ok(U256::MAX)
+        );
+        assert_eq!(
+            parse("115792089237316195423570985008687907853269984665640564039457584007913129639936"),
+            Err(FromBigDecimalError::ValueTooLarge)
+        );
+    }
+
+    #[test]
+    fn test_digit_bound() {
+        // The bound must not reject the largest value of any width.
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            assert_eq!(U::try_from(BigDecimal::from(U::MAX)), Ok(U::MAX));
+        });
+    }
+}
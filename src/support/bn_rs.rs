@@ -39,7 +39,8 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<BN> for Uint<BITS, LIMBS> {
 
 impl<const BITS: usize, const LIMBS: usize> From<&Uint<BITS, LIMBS>> for BN {
     fn from(value: &Uint<BITS, LIMBS>) -> Self {
-        Self::new_from_array(&value.as_le_bytes(), 256)
+        // `new BN(array)` reads the bytes as big-endian.
+        Self::new_from_array(&value.to_be_bytes_vec(), 256)
     }
 }
 
@@ -54,8 +55,12 @@ impl<const BITS: usize, const LIMBS: usize> TryFrom<&BigNumber> for Uint<BITS, L
 
     // FIXME: Return wrapped values.
     fn try_from(value: &BigNumber) -> Result<Self, Self::Error> {
+        // `_hex` is `0x` prefixed with a leading `-` for negative values.
         let hex = value.hex();
-        Self::from_str_radix(&hex, 16).map_err(|e| match e {
+        if hex.starts_with('-') {
+            return Err(ToUintError::ValueNegative(BITS, Self::ZERO));
+        }
+        Self::from_hex_str(&hex).map_err(|e| match e {
             ParseError::BaseConvertError(BaseConvertError::Overflow) => {
                 ToUintError::ValueTooLarge(BITS, Self::ZERO)
             }
@@ -140,6 +145,37 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_bn_decimal() {
+        type U = Uint<256, 4>;
+        let decimal = U::MAX.to_string();
+        let obj = BN::new(decimal.clone(), 10);
+        assert_eq!(U::try_from(&obj), Ok(U::MAX));
+        assert_eq!(BN::from(U::MAX).toString(10), decimal);
+        assert_eq!(
+            Uint::<255, 4>::try_from(&obj),
+            Err(ToUintError::ValueTooLarge(255, Uint::ZERO))
+        );
+        proptest!(|(value: U)| {
+            let obj = BN::new(value.to_string(), 10);
+            assert_eq!(obj.toString(10), value.to_string());
+            assert_eq!(U::try_from(obj), Ok(value));
+        });
+    }
+
+    #[test]
+    fn test_negative() {
+        type U = Uint<256, 4>;
+        assert_eq!(
+            U::try_from(BN::new("-1".into(), 10)),
+            Err(ToUintError::ValueNegative(256, U::ZERO))
+        );
+        assert_eq!(
+            U::try_from(BigNumber::new("-1".into())),
+            Err(ToUintError::ValueNegative(256, U::ZERO))
+        );
+    }
+
     #[test]
     fn test_bignumber_roundtrip() {
         const_for!(BITS in SIZES {
@@ -1,4 +1,8 @@
 //! Support for the [`fastrlp`](https://crates.io/crates/fastrlp) crate.
+//!
+//! Lists such as `Vec<Uint>` and `[Uint; N]` are covered by the generic
+//! `fastrlp` impls for vectors and arrays of [`Encodable`] and [`Decodable`]
+//! items.
 
 #![cfg(feature = "fastrlp-04")]
 #![cfg_attr(docsrs, doc(cfg(feature = "fastrlp-04")))]
@@ -168,4 +172,39 @@ mod test {
             });
         });
     }
+
+    #[test]
+    fn test_list_roundtrip() {
+        let empty: Vec<U256> = vec![];
+        assert_eq!(encode(&empty)[..], hex!("c0"));
+        assert_eq!(Vec::<U256>::decode(&mut &hex!("c0")[..]), Ok(empty));
+
+        let values = vec![U256::from(0), U256::from(15), U256::from(1024)];
+        let serialized = encode(&values);
+        assert_eq!(serialized[..], hex!("c580 0f 820400"));
+        assert_eq!(serialized.len(), values.length());
+        assert_eq!(
+            Vec::<U256>::decode(&mut &serialized[..]),
+            Ok(values.clone())
+        );
+
+        let array = [values[0], values[1], values[2]];
+        assert_eq!(encode(array), serialized);
+        assert_eq!(<[U256; 3]>::decode(&mut &serialized[..]), Ok(array));
+        assert_eq!(
+            <[U256; 2]>::decode(&mut &serialized[..]),
+            Err(DecodeError::ListLengthMismatch {
+                expected: 2,
+                got: 3
+            })
+        );
+
+        proptest!(|(values: Vec<U256>)| {
+            let serialized = encode(&values);
+            assert_eq!(serialized.len(), values.length());
+            let mut reader = &serialized[..];
+            assert_eq!(Vec::<U256>::decode(&mut reader), Ok(values));
+            assert_eq!(reader.len(), 0);
+        });
+    }
 }
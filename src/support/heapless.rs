@@ -0,0 +1,69 @@
+//! Support for the [`heapless`](https://crates.io/crates/heapless) crate.
+
+#![cfg(feature = "heapless")]
+#![cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+
+use crate::Uint;
+use heapless::Vec;
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Converts the [`Uint`] to a big-endian byte vector of size
+    /// [`Self::BYTES`] without allocating.
+    ///
+    /// This is the `no_std` counterpart of [`Uint::to_be_bytes_vec`]. The
+    /// capacity `N` must be at least [`Self::BYTES`], this is checked at
+    /// compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ruint::{uint, aliases::U24};
+    /// # uint!{
+    /// let bytes = 0x010203_U24.to_be_bytes_heapless::<4>();
+    /// assert_eq!(bytes.as_slice(), &[1, 2, 3]);
+    /// # }
+    /// ```
+    ///
+    /// A capacity below [`Self::BYTES`] does not compile:
+    ///
+    /// ```compile_fail
+    /// # use ruint::aliases::U256;
+    /// let bytes = U256::ZERO.to_be_bytes_heapless::<16>();
+    /// ```
+    #[must_use]
+    pub fn to_be_bytes_heapless<const N: usize>(&self) -> Vec<u8, N> {
+        let () = Capacity::<N, BITS>::OK;
+        let mut result = Vec::new();
+        for i in (0..Self::BYTES).rev() {
+            // Can not fail, capacity is checked above.
+            let _ = result.push(self.le_byte(i));
+        }
+        result
+    }
+}
+
+/// Compile time check of the capacity in [`Uint::to_be_bytes_heapless`].
+struct Capacity<const N: usize, const BITS: usize>;
+
+impl<const N: usize, const BITS: usize> Capacity<N, BITS> {
+    const OK: () = assert!(N >= (BITS + 7) / 8, "N must be at least Uint::BYTES");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::proptest;
+
+    #[test]
+    fn test_to_be_bytes_heapless() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let bytes = value.to_be_bytes_heapless::<512>();
+                assert_eq!(bytes.as_slice(), value.to_be_bytes_vec().as_slice());
+            });
+        });
+    }
+}
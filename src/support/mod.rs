@@ -6,6 +6,7 @@ mod alloy_rlp;
 mod arbitrary;
 mod ark_ff;
 mod ark_ff_04;
+pub mod bigdecimal;
 mod bn_rs;
 mod borsh;
 mod bytemuck;
@@ -13,6 +14,7 @@ mod der;
 pub mod diesel;
 mod fastrlp_03;
 mod fastrlp_04;
+mod heapless;
 mod num_bigint;
 mod num_integer;
 mod num_traits;
@@ -24,7 +26,7 @@ mod quickcheck;
 mod rand;
 mod rlp;
 pub mod scale;
-mod serde;
+pub mod serde;
 pub mod sqlx;
 pub mod ssz;
 mod subtle;
@@ -40,7 +42,6 @@ mod zeroize;
 // Big int types:
 // * https://crates.io/crates/crypto-bigint
 // * https://crates.io/crates/rug
-// * https://crates.io/crates/bigdecimal
 // * https://crates.io/crates/rust_decimal
 
 // * wasm-bindgen `JsValue` bigint: https://docs.rs/wasm-bindgen/latest/wasm_bindgen/struct.JsValue.html#method.bigint_from_str
@@ -109,4 +109,34 @@ mod tests {
             });
         });
     }
+
+    #[test]
+    fn test_full_mul() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            const BITS_RES: usize = 2 * BITS;
+            const LIMBS_RES: usize = nlimbs(BITS_RES);
+            proptest!(|(a: U, b: U)| {
+                let product: BigUint = a.full_mul::<BITS_RES, LIMBS_RES>(b).into();
+                assert_eq!(product, BigUint::from(a) * BigUint::from(b));
+            });
+        });
+    }
+
+    #[test]
+    fn test_checked_mul_widening() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            const BITS_RES: usize = BITS + 32;
+            const LIMBS_RES: usize = nlimbs(BITS_RES);
+            proptest!(|(a: U, b: Uint<64, 1>)| {
+                let expected = BigUint::from(a) * BigUint::from(b);
+                let fits = expected.bits() <= BITS_RES as u64;
+                let product = a.checked_mul_widening::<64, 1, BITS_RES, LIMBS_RES>(b);
+                assert_eq!(product.map(BigUint::from), fits.then_some(expected));
+            });
+        });
+    }
 }
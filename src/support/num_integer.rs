@@ -2,7 +2,7 @@
 
 #![cfg(feature = "num-integer")]
 
-use crate::Uint;
+use crate::{algorithms, Uint};
 use num_integer::{ExtendedGcd, Integer};
 
 impl<const BITS: usize, const LIMBS: usize> Integer for Uint<BITS, LIMBS> {
@@ -10,14 +10,14 @@ impl<const BITS: usize, const LIMBS: usize> Integer for Uint<BITS, LIMBS> {
     #[must_use]
     #[track_caller]
     fn div_floor(&self, other: &Self) -> Self {
-        Self::wrapping_div(*self, *other)
+        *self / *other
     }
 
     #[inline]
     #[must_use]
     #[track_caller]
     fn mod_floor(&self, other: &Self) -> Self {
-        Self::wrapping_rem(*self, *other)
+        *self % *other
     }
 
     #[inline]
@@ -72,7 +72,7 @@ impl<const BITS: usize, const LIMBS: usize> Integer for Uint<BITS, LIMBS> {
 
     #[inline]
     fn extended_gcd(&self, other: &Self) -> ExtendedGcd<Self> {
-        let (gcd, x, y, _sign) = <Self>::gcd_extended(*self, *other);
+        let (gcd, x, y, _sign) = algorithms::gcd_extended(*self, *other);
         ExtendedGcd { gcd, x, y }
     }
 
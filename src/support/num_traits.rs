@@ -161,6 +161,8 @@ impl<const BITS: usize, const LIMBS: usize> CheckedEuclid for Uint<BITS, LIMBS>
     }
 }
 
+// Unlike the primitive integers, division by zero does not panic here but
+// follows the documented `wrapping_div` and `wrapping_rem` results.
 impl<const BITS: usize, const LIMBS: usize> Euclid for Uint<BITS, LIMBS> {
     #[inline(always)]
     fn div_euclid(&self, v: &Self) -> Self {
@@ -470,7 +472,10 @@ impl<const BITS: usize, const LIMBS: usize> PrimInt for Uint<BITS, LIMBS> {
 mod tests {
     use super::*;
     use crate::aliases::{U256, U64};
-    use num_traits::bounds::{LowerBounded, UpperBounded};
+    use num_traits::{
+        bounds::{LowerBounded, UpperBounded},
+        NumAssignOps, NumOps, NumRef,
+    };
 
     macro_rules! assert_impl{
         ($type:ident, $($trait:tt),*) => {
@@ -487,6 +492,7 @@ mod tests {
         assert_impl!(U256, Bounded, LowerBounded, UpperBounded);
         assert_impl!(U256, FromPrimitive, NumCast, ToPrimitive);
         assert_impl!(U256, One, Zero);
+        assert_impl!(U256, Num, NumOps, NumAssignOps, NumRef);
         assert_impl!(U256, PrimInt);
         assert_impl!(U256, FromBytes, ToBytes);
         assert_impl!(
@@ -517,6 +523,30 @@ mod tests {
         assert_impl!(U256, Unsigned);
     }
 
+    #[test]
+    fn test_generic_num() {
+        // Uint should be usable wherever a generic numeric type is expected.
+        fn square_sum<T: Num + Bounded + CheckedMul + Copy>(values: &[T]) -> T {
+            values
+                .iter()
+                .try_fold(T::zero(), |acc, &v| v.checked_mul(&v).map(|sq| acc + sq))
+                .unwrap_or_else(T::max_value)
+        }
+
+        let values = [U256::from(3), U256::from(4)];
+        assert_eq!(square_sum(&values), U256::from(25));
+        assert_eq!(square_sum(&[U256::MAX]), U256::MAX);
+        assert_eq!(square_sum::<U256>(&[]), U256::ZERO);
+    }
+
+    #[test]
+    fn test_euclid_by_zero() {
+        let n = U256::from(42);
+        assert_eq!(Euclid::div_euclid(&n, &U256::ZERO), U256::MAX);
+        assert_eq!(Euclid::rem_euclid(&n, &U256::ZERO), n);
+        assert_eq!(CheckedEuclid::checked_div_euclid(&n, &U256::ZERO), None);
+    }
+
     #[test]
     fn test_signed_shl() {
         // Example from num-traits docs.
@@ -30,6 +30,9 @@ impl_uint_froms!(ours::U512, U512);
 
 /// Hash types (H128, H160, H256, H512) in `primitive-types` are stored as
 /// big-endian order bytes.
+///
+/// This is used for both [`Bits`](crate::Bits) and [`Uint`](crate::Uint), so
+/// `H256::from(U256::from(1))` has its last byte set.
 macro_rules! impl_bits_froms {
     ($ours:ty, $theirs:ident) => {
         impl From<$theirs> for $ours {
@@ -51,6 +54,11 @@ impl_bits_froms!(ours::B160, H160);
 impl_bits_froms!(ours::B256, H256);
 impl_bits_froms!(ours::B512, H512);
 
+impl_bits_froms!(ours::U128, H128);
+impl_bits_froms!(ours::U160, H160);
+impl_bits_froms!(ours::U256, H256);
+impl_bits_froms!(ours::U512, H512);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -77,5 +85,22 @@ mod tests {
         test_roundtrip::<ours::B160, H160>();
         test_roundtrip::<ours::B256, H256>();
         test_roundtrip::<ours::B512, H512>();
+        test_roundtrip::<ours::U128, H128>();
+        test_roundtrip::<ours::U160, H160>();
+        test_roundtrip::<ours::U256, H256>();
+        test_roundtrip::<ours::U512, H512>();
+    }
+
+    #[test]
+    fn test_hash_big_endian() {
+        let mut bytes = [0; 32];
+        bytes[31] = 1;
+        assert_eq!(H256::from(ours::U256::from(1)), H256(bytes));
+        proptest!(|(value: ours::U256)| {
+            assert_eq!(H256::from(value).0, value.to_be_bytes::<32>());
+        });
+        proptest!(|(value: ours::U160)| {
+            assert_eq!(H160::from(value).0, value.to_be_bytes::<20>());
+        });
     }
 }
@@ -24,6 +24,27 @@ impl<const BITS: usize, const LIMBS: usize> Distribution<Uint<BITS, LIMBS>> for
     }
 }
 
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Creates a uniformly random value using the thread-local random number
+    /// generator.
+    ///
+    /// Convenience for [`Self::random_with`] with [`rand::thread_rng`].
+    #[cfg(feature = "std")]
+    #[inline]
+    #[must_use]
+    pub fn random() -> Self {
+        Self::random_with(&mut rand::thread_rng())
+    }
+
+    /// Creates a uniformly random value using the given random number
+    /// generator.
+    #[inline]
+    #[must_use]
+    pub fn random_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -39,4 +60,14 @@ mod tests {
             }
         });
     }
+
+    #[test]
+    fn test_random() {
+        type U = Uint<256, 4>;
+        // Collisions have probability 2^-256.
+        assert_ne!(U::random(), U::random());
+        let mut rng = rand::thread_rng();
+        assert_ne!(U::random_with(&mut rng), U::random_with(&mut rng));
+        assert_eq!(Uint::<0, 0>::random(), Uint::ZERO);
+    }
 }
@@ -21,17 +21,28 @@ impl<const BITS: usize, const LIMBS: usize> Encodable for Uint<BITS, LIMBS> {
 
 /// Allows a [`Uint`] to be deserialized from RLP.
 ///
+/// Like the `rlp` integer impls, this rejects non-canonical encodings with
+/// leading zero bytes.
+///
 /// See <https://eth.wiki/en/fundamentals/rlp>
 impl<const BITS: usize, const LIMBS: usize> Decodable for Uint<BITS, LIMBS> {
     fn decode(s: &Rlp) -> Result<Self, DecoderError> {
-        Self::try_from_be_slice(s.data()?).ok_or(DecoderError::Custom(
-            "RLP integer value too large for Uint.",
-        ))
+        s.decoder().decode_value(|bytes| match bytes.first() {
+            Some(0) => Err(DecoderError::RlpInvalidIndirection),
+            _ => Self::try_from_be_slice(bytes).ok_or(DecoderError::Custom(
+                "RLP integer value too large for Uint.",
+            )),
+        })
     }
 }
 
 /// Allows a [`Bits`] to be serialized as RLP.
 ///
+/// Unlike [`Uint`], this is the fixed-width big-endian byte string without
+/// trimming, matching the encoding of hashes and addresses such as `H160`.
+/// A [`Bits`] is a bit string rather than an integer, so leading zero bytes
+/// are significant and are not treated as non-canonical.
+///
 /// See <https://eth.wiki/en/fundamentals/rlp>
 impl<const BITS: usize, const LIMBS: usize> Encodable for Bits<BITS, LIMBS> {
     #[allow(clippy::collection_is_never_read)] // have to use vec
@@ -44,6 +55,8 @@ impl<const BITS: usize, const LIMBS: usize> Encodable for Bits<BITS, LIMBS> {
 
 /// Allows a [`Bits`] to be deserialized from RLP.
 ///
+/// The byte string must be exactly [`Bits::BYTES`] long.
+///
 /// See <https://eth.wiki/en/fundamentals/rlp>
 impl<const BITS: usize, const LIMBS: usize> Decodable for Bits<BITS, LIMBS> {
     fn decode(s: &Rlp) -> Result<Self, DecoderError> {
@@ -93,6 +106,31 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_uint_non_canonical() {
+        let decode = |bytes: &[u8]| U256::decode(&Rlp::new(bytes));
+        assert_eq!(decode(&hex!("80")), Ok(U256::ZERO));
+        assert_eq!(decode(&hex!("820400")), Ok(U256::from(1024)));
+        assert_eq!(
+            decode(&hex!("00")),
+            Err(DecoderError::RlpInvalidIndirection)
+        );
+        assert_eq!(
+            decode(&hex!("8100")),
+            Err(DecoderError::RlpInvalidIndirection)
+        );
+        assert_eq!(
+            decode(&hex!("83000400")),
+            Err(DecoderError::RlpInvalidIndirection)
+        );
+        // Single bytes below 0x80 must not be length-prefixed.
+        assert_eq!(
+            decode(&hex!("810f")),
+            Err(DecoderError::RlpInvalidIndirection)
+        );
+        assert!(Uint::<8, 1>::decode(&Rlp::new(&hex!("820100"))).is_err());
+    }
+
     #[test]
     fn test_bits_rlp() {
         // See <https://github.com/paritytech/parity-common/blob/09371a1c63e315c9c390a9c761f1863a5b97be47/rlp/tests/tests.rs#L271-L278>
@@ -116,4 +154,23 @@ mod test {
             });
         });
     }
+
+    #[test]
+    fn test_bits_wrong_length() {
+        let decode = |bytes: &[u8]| B160::decode(&Rlp::new(bytes));
+        assert_eq!(decode(&hex!("80")), Err(DecoderError::RlpIsTooShort));
+        assert_eq!(
+            decode(&hex!("932d6d194084c2de36e0dabfce45d046b37d1106")),
+            Err(DecoderError::RlpIsTooShort)
+        );
+        assert_eq!(
+            decode(&hex!("9500ef2d6d194084c2de36e0dabfce45d046b37d1106")),
+            Err(DecoderError::RlpIsTooBig)
+        );
+        // Leading zeros are part of the fixed width encoding.
+        assert_eq!(
+            decode(&hex!("940000000000000000000000000000000000000001")),
+            Ok(B160::from(Uint::from(1)))
+        );
+    }
 }
@@ -9,7 +9,7 @@ use core::{
     str,
 };
 use serde::{
-    de::{Error, Unexpected, Visitor},
+    de::{Error, SeqAccess, Unexpected, Visitor},
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
@@ -65,10 +65,13 @@ impl<const BITS: usize, const LIMBS: usize> Serialize for Uint<BITS, LIMBS> {
     }
 }
 
-/// Deserialize human readable hex strings or byte arrays into [`Uint`].
+/// Deserialize human readable strings and integers, or byte arrays into
+/// [`Uint`].
 ///
-/// Hex strings can be upper/lower/mixed case, have an optional `0x` prefix, and
-/// can be any length. They are interpreted big-endian.
+/// Human readable formats accept `0x` prefixed hex strings in upper, lower or
+/// mixed case and of any length, decimal strings, `0o` and `0b` prefixed
+/// strings, and non-negative integers that fit the [`Uint`]. This makes
+/// hand-written config files parse, while serialization stays canonical hex.
 impl<'de, const BITS: usize, const LIMBS: usize> Deserialize<'de> for Uint<BITS, LIMBS> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         if deserializer.is_human_readable() {
@@ -104,13 +107,26 @@ impl<const BITS: usize, const LIMBS: usize> Visitor<'_> for HrVisitor<BITS, LIMB
     type Value = Uint<BITS, LIMBS>;
 
     fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
-        write!(formatter, "a {} byte hex string", nbytes(BITS))
+        write!(
+            formatter,
+            "a hex or decimal string or a non-negative integer of at most {BITS} bits"
+        )
     }
 
     fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
         Uint::try_from(v).map_err(|_| Error::invalid_value(Unexpected::Unsigned(v), &self))
     }
 
+    // Formats like TOML report all integers as signed.
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        Uint::try_from(v).map_err(|_| Error::invalid_value(Unexpected::Signed(v), &self))
+    }
+
+    fn visit_i128<E: Error>(self, v: i128) -> Result<Self::Value, E> {
+        // `Unexpected::Signed` cannot contain an `i128`
+        Uint::try_from(v).map_err(Error::custom)
+    }
+
     fn visit_u128<E: Error>(self, v: u128) -> Result<Self::Value, E> {
         // `Unexpected::Unsigned` cannot contain a `u128`
         Uint::try_from(v).map_err(Error::custom)
@@ -156,6 +172,83 @@ impl<const BITS: usize, const LIMBS: usize> Visitor<'_> for ByteVisitor<BITS, LI
     }
 }
 
+/// Serialize a [`Uint`] as its raw array of `u64` limbs.
+///
+/// Limbs are least significant first, the same as [`Uint::as_limbs`]. This
+/// reproduces the exact bit pattern independent of byte order, which is useful
+/// for test fixtures. Use it with `#[serde(with =
+/// "ruint::support::serde::limbs")]`.
+///
+/// # Examples
+///
+/// ```
+/// # use ruint::{uint, aliases::*, support::serde::limbs};
+/// # uint!{
+/// let mut json = Vec::new();
+/// limbs::serialize(
+///     &0x1_0000000000000002_U128,
+///     &mut serde_json::Serializer::new(&mut json),
+/// )
+/// .unwrap();
+/// assert_eq!(json, b"[2,1]");
+///
+/// let value: U128 = limbs::deserialize(&mut serde_json::Deserializer::from_slice(&json)).unwrap();
+/// assert_eq!(value, 0x1_0000000000000002_U128);
+/// # }
+/// ```
+pub mod limbs {
+    use super::{Deserializer, Error, FmtResult, Formatter, SeqAccess, Serializer, Uint, Visitor};
+
+    /// Serialize the limbs as a sequence of `u64`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails.
+    pub fn serialize<S: Serializer, const BITS: usize, const LIMBS: usize>(
+        value: &Uint<BITS, LIMBS>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(value.as_limbs())
+    }
+
+    /// Deserialize a sequence of exactly `LIMBS` limbs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sequence does not have `LIMBS` elements or if
+    /// the value is too large for the [`Uint`].
+    pub fn deserialize<'de, D: Deserializer<'de>, const BITS: usize, const LIMBS: usize>(
+        deserializer: D,
+    ) -> Result<Uint<BITS, LIMBS>, D::Error> {
+        deserializer.deserialize_seq(LimbsVisitor)
+    }
+
+    /// Serde Visitor for limb arrays.
+    struct LimbsVisitor<const BITS: usize, const LIMBS: usize>;
+
+    impl<'de, const BITS: usize, const LIMBS: usize> Visitor<'de> for LimbsVisitor<BITS, LIMBS> {
+        type Value = Uint<BITS, LIMBS>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+            write!(formatter, "an array of {LIMBS} u64 limbs for Uint<{BITS}>")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut limbs = [0; LIMBS];
+            for (i, limb) in limbs.iter_mut().enumerate() {
+                *limb = seq
+                    .next_element()?
+                    .ok_or_else(|| Error::invalid_length(i, &self))?;
+            }
+            if seq.next_element::<u64>()?.is_some() {
+                return Err(Error::invalid_length(LIMBS + 1, &self));
+            }
+            Uint::checked_from_limbs(limbs)
+                .ok_or_else(|| Error::custom(format!("value too large for Uint<{BITS}>")))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -207,6 +300,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_human_readable_lenient() {
+        use serde::de::{value::Error as ValueError, IntoDeserializer};
+
+        let expected = Uint::<256, 4>::from(1_000_000_u64);
+        let jason = r#"[
+            "0xf4240",
+            "0x00000000000000000000000000000000000000000000000000000000000F4240",
+            "1000000",
+            1000000
+        ]"#;
+        let numbers: Vec<Uint<256, 4>> = serde_json::from_str(jason).unwrap();
+        assert_eq!(numbers, vec![expected; 4]);
+        let large =
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+        let value: Uint<256, 4> = serde_json::from_str(&format!("\"{large}\"")).unwrap();
+        assert_eq!(value, Uint::MAX);
+
+        let value: Result<Uint<256, 4>, ValueError> =
+            Uint::deserialize(1_000_000_i64.into_deserializer());
+        assert_eq!(value, Ok(expected));
+        let value: Result<Uint<256, 4>, ValueError> =
+            Uint::deserialize(1_000_000_i128.into_deserializer());
+        assert_eq!(value, Ok(expected));
+        let value: Result<Uint<256, 4>, ValueError> =
+            Uint::deserialize((-1_i64).into_deserializer());
+        assert!(value.is_err());
+        let value: Result<Uint<256, 4>, ValueError> =
+            Uint::deserialize((-1_i128).into_deserializer());
+        assert!(value.is_err());
+        let value: Result<Uint<8, 1>, ValueError> = Uint::deserialize(256_i64.into_deserializer());
+        assert!(value.is_err());
+
+        assert!(serde_json::from_str::<Uint<256, 4>>("-1").is_err());
+        assert!(serde_json::from_str::<Uint<256, 4>>("1.5").is_err());
+        assert!(serde_json::from_str::<Uint<8, 1>>("256").is_err());
+        assert!(serde_json::from_str::<Uint<8, 1>>("\"256\"").is_err());
+    }
+
     #[test]
     fn test_serde_machine_readable() {
         const_for!(BITS in SIZES {
@@ -224,6 +356,29 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_serde_limbs() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            proptest!(|(value: Uint<BITS, LIMBS>)| {
+                let mut json = Vec::new();
+                limbs::serialize(&value, &mut serde_json::Serializer::new(&mut json)).unwrap();
+                let expected = format!("{:?}", value.as_limbs()).replace(' ', "");
+                assert_eq!(json, expected.as_bytes());
+                let mut deserializer = serde_json::Deserializer::from_slice(&json);
+                assert_eq!(limbs::deserialize::<_, BITS, LIMBS>(&mut deserializer).unwrap(), value);
+            });
+        });
+        let mut deserializer = serde_json::Deserializer::from_str("[1,2]");
+        assert!(limbs::deserialize::<_, 128, 2>(&mut deserializer).is_ok());
+        let mut deserializer = serde_json::Deserializer::from_str("[1]");
+        assert!(limbs::deserialize::<_, 128, 2>(&mut deserializer).is_err());
+        let mut deserializer = serde_json::Deserializer::from_str("[1,2,3]");
+        assert!(limbs::deserialize::<_, 128, 2>(&mut deserializer).is_err());
+        let mut deserializer = serde_json::Deserializer::from_str("[1,2]");
+        assert!(limbs::deserialize::<_, 65, 2>(&mut deserializer).is_err());
+    }
+
     #[test]
     fn test_serde_invalid_size_error() {
         // Test that if we add a character to a value that is already the max length for
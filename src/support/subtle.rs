@@ -22,10 +22,18 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         let (limbs, bits) = (index / 64, index % 64);
         (self.limbs[limbs] & (1 << bits)).ct_eq(&(1 << bits))
     }
-}
 
-impl<const BITS: usize, const LIMBS: usize> ConditionallySelectable for Uint<BITS, LIMBS> {
-    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+    /// Returns `b` if `choice` is set and `a` otherwise.
+    ///
+    /// The selection is done limb-by-limb using masking, without branching
+    /// on `choice` or on the values. This is best-effort: as with [`subtle`]
+    /// in general, the compiler is not guaranteed to preserve this.
+    ///
+    /// This is also available as [`ConditionallySelectable`], but does not
+    /// require the trait to be in scope.
+    #[inline]
+    #[must_use]
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         let mut limbs = [0_u64; LIMBS];
         for (limb, (a, b)) in limbs
             .iter_mut()
@@ -35,6 +43,34 @@ impl<const BITS: usize, const LIMBS: usize> ConditionallySelectable for Uint<BIT
         }
         Self::from_limbs(limbs)
     }
+
+    /// Assigns `other` to `self` if `choice` is set, in place and without
+    /// branching. See [`Uint::conditional_select`].
+    #[inline]
+    pub fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        for (limb, other) in self.limbs.iter_mut().zip(other.as_limbs().iter()) {
+            limb.conditional_assign(other, choice);
+        }
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ConditionallySelectable for Uint<BITS, LIMBS> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::conditional_select(a, b, choice)
+    }
+
+    #[inline]
+    fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        Self::conditional_assign(self, other, choice);
+    }
+
+    #[inline]
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        for (a, b) in a.limbs.iter_mut().zip(b.limbs.iter_mut()) {
+            u64::conditional_swap(a, b, choice);
+        }
+    }
 }
 
 impl<const BITS: usize, const LIMBS: usize> ConstantTimeEq for Uint<BITS, LIMBS> {
@@ -116,6 +152,24 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_assign_swap() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(a: U, b: U, c: bool)| {
+                let choice = Choice::from(c as u8);
+                let mut r = a;
+                r.conditional_assign(&b, choice);
+                assert_eq!(r, if c { b } else { a });
+
+                let (mut x, mut y) = (a, b);
+                U::conditional_swap(&mut x, &mut y, choice);
+                assert_eq!((x, y), if c { (b, a) } else { (a, b) });
+            });
+        });
+    }
+
     #[test]
     fn test_negate() {
         const_for!(BITS in SIZES {